@@ -0,0 +1,41 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use monotonic_time_rs::{
+    InstantMonotonicClock, Millis, MillisDuration, MinResolutionClock, MonotonicClock,
+    WallAnchoredClock,
+};
+
+fn now_overhead(c: &mut Criterion) {
+    let instant = InstantMonotonicClock::new();
+    c.bench_function("InstantMonotonicClock::now", |b| {
+        b.iter(|| black_box(&instant).now())
+    });
+
+    let coarse = MinResolutionClock::new(
+        InstantMonotonicClock::new(),
+        MillisDuration::from_millis(10),
+    );
+    c.bench_function("MinResolutionClock::now (10 ms)", |b| {
+        b.iter(|| black_box(&coarse).now())
+    });
+
+    let wall = WallAnchoredClock::new();
+    c.bench_function("WallAnchoredClock::now", |b| {
+        b.iter(|| black_box(&wall).now())
+    });
+
+    let dynamic: &dyn MonotonicClock = &instant;
+    c.bench_function("dyn MonotonicClock::now", |b| {
+        b.iter(|| black_box(dynamic).now())
+    });
+
+    c.bench_function("Millis::new baseline", |b| {
+        b.iter(|| black_box(Millis::new(black_box(1000))))
+    });
+}
+
+criterion_group!(benches, now_overhead);
+criterion_main!(benches);
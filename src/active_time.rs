@@ -0,0 +1,86 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MillisDuration, MonotonicClock};
+use std::sync::Mutex;
+
+#[derive(Debug)]
+struct ActiveState {
+    last_heartbeat: Millis,
+    active: Millis,
+}
+
+/// A `MonotonicClock` decorator that leaves out time the process was suspended.
+///
+/// The application calls [`ActiveTimeClock::heartbeat`] periodically, well within `max_gap`.
+/// Time between two heartbeats is counted, but a gap longer than `max_gap` is taken to be a
+/// suspend and only counts as `max_gap`. This gives the same behavior across platforms,
+/// whether or not their `Instant` includes sleep.
+///
+/// `now()` starts at the inner clock's reading when the clock is created.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{ActiveTimeClock, InstantMonotonicClock, MillisDuration, MonotonicClock};
+/// let clock = ActiveTimeClock::new(InstantMonotonicClock::new(), MillisDuration::from_millis(1000));
+/// let start = clock.now();
+/// clock.heartbeat();
+/// assert!(clock.now() >= start);
+/// ```
+#[derive(Debug)]
+pub struct ActiveTimeClock<C> {
+    inner: C,
+    max_gap: MillisDuration,
+    state: Mutex<ActiveState>,
+}
+
+impl<C: MonotonicClock> ActiveTimeClock<C> {
+    /// Wraps `inner`, treating gaps between heartbeats longer than `max_gap` as suspends.
+    pub fn new(inner: C, max_gap: MillisDuration) -> Self {
+        let now = inner.now();
+        Self {
+            inner,
+            max_gap,
+            state: Mutex::new(ActiveState {
+                last_heartbeat: now,
+                active: now,
+            }),
+        }
+    }
+
+    /// Counts the time since the previous heartbeat, capped at `max_gap`.
+    pub fn heartbeat(&self) {
+        let mut state = self.state.lock().unwrap();
+        let now = self.inner.now();
+        state.active = self.active_at(&state, now);
+        state.last_heartbeat = now;
+    }
+
+    /// Returns the longest gap between heartbeats that is counted in full.
+    #[inline]
+    pub fn max_gap(&self) -> MillisDuration {
+        self.max_gap
+    }
+
+    /// Returns a reference to the wrapped clock.
+    #[inline]
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    fn active_at(&self, state: &ActiveState, now: Millis) -> Millis {
+        let gap = now
+            .checked_duration_since_ms(state.last_heartbeat)
+            .unwrap_or_default();
+        state.active + gap.min(self.max_gap)
+    }
+}
+
+impl<C: MonotonicClock> MonotonicClock for ActiveTimeClock<C> {
+    fn now(&self) -> Millis {
+        let state = self.state.lock().unwrap();
+        self.active_at(&state, self.inner.now())
+    }
+}
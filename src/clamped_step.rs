@@ -0,0 +1,76 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MillisDuration, MonotonicClock};
+use std::sync::Mutex;
+
+/// A `MonotonicClock` decorator that limits how far time can advance per `now()` call.
+///
+/// Each reading is clamped to at most `max_step` after the previously returned value. After a
+/// long stall, such as the process being suspended, the reported time advances by `max_step` per
+/// call and lags behind the inner clock until it has caught up. The first call returns the inner
+/// reading unchanged, and the reported time never decreases.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{ClampedStepClock, Millis, MillisDuration, MonotonicClock};
+/// use std::cell::Cell;
+///
+/// struct JumpingClock(Cell<u64>);
+///
+/// impl MonotonicClock for JumpingClock {
+///     fn now(&self) -> Millis {
+///         let now = self.0.get();
+///         self.0.set(now + 10_000);
+///         Millis::new(now)
+///     }
+/// }
+///
+/// let clock = ClampedStepClock::new(JumpingClock(Cell::new(0)), MillisDuration::from_millis(100));
+/// assert_eq!(clock.now(), Millis::new(0));
+/// assert_eq!(clock.now(), Millis::new(100));
+/// ```
+#[derive(Debug)]
+pub struct ClampedStepClock<C> {
+    inner: C,
+    max_step: MillisDuration,
+    last: Mutex<Option<Millis>>,
+}
+
+impl<C: MonotonicClock> ClampedStepClock<C> {
+    /// Wraps `inner` so that each `now()` advances by at most `max_step`.
+    pub fn new(inner: C, max_step: MillisDuration) -> Self {
+        Self {
+            inner,
+            max_step,
+            last: Mutex::new(None),
+        }
+    }
+
+    /// Returns the maximum step per `now()` call.
+    #[inline]
+    pub fn max_step(&self) -> MillisDuration {
+        self.max_step
+    }
+
+    /// Returns a reference to the wrapped clock.
+    #[inline]
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: MonotonicClock> MonotonicClock for ClampedStepClock<C> {
+    fn now(&self) -> Millis {
+        let mut last = self.last.lock().unwrap();
+        let raw = self.inner.now();
+        let now = match *last {
+            None => raw,
+            Some(previous) => raw.clamp(previous, previous.saturating_add(self.max_step)),
+        };
+        *last = Some(now);
+        now
+    }
+}
@@ -0,0 +1,59 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MonotonicClock};
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// A `MonotonicClock` decorator that counts how often `now()` is called.
+///
+/// A lightweight alternative to `RecordingClock` for finding hot paths that read the
+/// clock excessively.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{CountingClock, InstantMonotonicClock, MonotonicClock};
+/// let clock = CountingClock::new(InstantMonotonicClock::new());
+/// clock.now();
+/// clock.now();
+/// assert_eq!(clock.call_count(), 2);
+/// ```
+#[derive(Debug)]
+pub struct CountingClock<C> {
+    inner: C,
+    calls: AtomicU64,
+}
+
+impl<C: MonotonicClock> CountingClock<C> {
+    /// Wraps `inner` with a call count of zero.
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            calls: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the number of `now()` calls so far.
+    pub fn call_count(&self) -> u64 {
+        self.calls.load(Ordering::Relaxed)
+    }
+
+    /// Resets the call count to zero.
+    pub fn reset_count(&self) {
+        self.calls.store(0, Ordering::Relaxed);
+    }
+
+    /// Returns a reference to the wrapped clock.
+    #[inline]
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: MonotonicClock> MonotonicClock for CountingClock<C> {
+    fn now(&self) -> Millis {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        self.inner.now()
+    }
+}
@@ -0,0 +1,64 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MonotonicClock};
+#[cfg(debug_assertions)]
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// A `MonotonicClock` decorator that verifies the inner clock never goes backwards.
+///
+/// In debug builds (`debug_assertions`), `now()` panics if a reading is earlier than a previous
+/// one. In release builds it is a transparent pass-through.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{DebugMonotonicClock, InstantMonotonicClock, MonotonicClock};
+/// let clock = DebugMonotonicClock::new(InstantMonotonicClock::new());
+/// let first = clock.now();
+/// assert!(clock.now() >= first);
+/// ```
+#[derive(Debug)]
+pub struct DebugMonotonicClock<C> {
+    inner: C,
+    #[cfg(debug_assertions)]
+    last: AtomicU64,
+}
+
+impl<C: MonotonicClock> DebugMonotonicClock<C> {
+    /// Wraps `inner` with a monotonicity check.
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            #[cfg(debug_assertions)]
+            last: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns a reference to the wrapped clock.
+    #[inline]
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: MonotonicClock> MonotonicClock for DebugMonotonicClock<C> {
+    #[inline]
+    fn now(&self) -> Millis {
+        let now = self.inner.now();
+        #[cfg(debug_assertions)]
+        {
+            let previous = self
+                .last
+                .fetch_max(now.absolute_milliseconds(), Ordering::Relaxed);
+            assert!(
+                now.absolute_milliseconds() >= previous,
+                "non-monotonic clock: {} is earlier than previous reading {} ms",
+                now,
+                previous
+            );
+        }
+        now
+    }
+}
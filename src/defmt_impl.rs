@@ -0,0 +1,18 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MillisDuration};
+use defmt::{Format, Formatter};
+
+impl Format for Millis {
+    fn format(&self, f: Formatter) {
+        defmt::write!(f, "{=u64} ms", self.absolute_milliseconds());
+    }
+}
+
+impl Format for MillisDuration {
+    fn format(&self, f: Formatter) {
+        defmt::write!(f, "{=u64} ms", self.as_millis());
+    }
+}
@@ -0,0 +1,65 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{float, Millis, SignedMillisDuration};
+
+/// Tracks a running estimate of the offset between a local and an authoritative clock.
+///
+/// Each observation folds `authoritative - local` into an exponential moving average where the
+/// newest observation has weight `weight`. The first observation is taken as is.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{DriftEstimator, Millis, SignedMillisDuration};
+/// let mut estimator = DriftEstimator::new(0.1);
+/// estimator.observe(Millis::new(1000), Millis::new(1040));
+/// assert_eq!(estimator.estimated_offset(), SignedMillisDuration::from_millis(40));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DriftEstimator {
+    weight: f64,
+    offset: Option<f64>,
+}
+
+impl DriftEstimator {
+    /// Creates an estimator without observations, giving each new one `weight`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weight` is not in the range `(0.0, 1.0]`.
+    pub fn new(weight: f64) -> Self {
+        assert!(
+            weight > 0.0 && weight <= 1.0,
+            "DriftEstimator weight must be in the range (0.0, 1.0]"
+        );
+        Self {
+            weight,
+            offset: None,
+        }
+    }
+
+    /// Folds the offset `authoritative - local` into the estimate.
+    pub fn observe(&mut self, local: Millis, authoritative: Millis) {
+        let sample =
+            authoritative.absolute_milliseconds() as f64 - local.absolute_milliseconds() as f64;
+        self.offset = Some(match self.offset {
+            None => sample,
+            Some(offset) => offset + self.weight * (sample - offset),
+        });
+    }
+
+    /// Returns the estimated `authoritative - local` offset, rounded to whole milliseconds.
+    ///
+    /// Returns zero before the first observation.
+    pub fn estimated_offset(&self) -> SignedMillisDuration {
+        SignedMillisDuration::from_millis(float::round(self.offset.unwrap_or_default()) as i64)
+    }
+
+    /// Returns `true` if at least one observation has been made.
+    #[inline]
+    pub fn has_observations(&self) -> bool {
+        self.offset.is_some()
+    }
+}
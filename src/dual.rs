@@ -0,0 +1,104 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MonotonicClock};
+use std::sync::Mutex;
+
+#[derive(Debug)]
+struct SmoothingState {
+    average: Option<f64>,
+    last_reported: Millis,
+}
+
+/// A `MonotonicClock` decorator that exposes both the raw reading and a smoothed estimate.
+///
+/// Every reading of the inner clock, whether through [`DualClock::now_raw`] or
+/// [`DualClock::now_smoothed`], is folded into an exponential moving average where the newest
+/// reading has weight `weight`. The smoothed value lags behind the raw one after a jump, and
+/// jitter in the raw readings is averaged out. Smoothed values never decrease.
+///
+/// `now()` reports the smoothed value.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{DualClock, InstantMonotonicClock, MonotonicClock};
+/// let clock = DualClock::new(InstantMonotonicClock::new(), 0.2);
+/// let raw = clock.now_raw();
+/// assert!(clock.now_smoothed() <= clock.now_raw());
+/// assert!(clock.now() >= raw);
+/// ```
+#[derive(Debug)]
+pub struct DualClock<C> {
+    inner: C,
+    weight: f64,
+    state: Mutex<SmoothingState>,
+}
+
+impl<C: MonotonicClock> DualClock<C> {
+    /// Wraps `inner`, giving each new reading `weight` in the moving average.
+    ///
+    /// A weight of `1.0` disables smoothing, while smaller weights smooth more and lag longer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weight` is not in the range `(0.0, 1.0]`.
+    pub fn new(inner: C, weight: f64) -> Self {
+        assert!(
+            weight > 0.0 && weight <= 1.0,
+            "DualClock weight must be in the range (0.0, 1.0]"
+        );
+        Self {
+            inner,
+            weight,
+            state: Mutex::new(SmoothingState {
+                average: None,
+                last_reported: Millis::new(0),
+            }),
+        }
+    }
+
+    /// Reads the inner clock and returns the reading unchanged.
+    pub fn now_raw(&self) -> Millis {
+        let mut state = self.state.lock().unwrap();
+        self.sample(&mut state)
+    }
+
+    /// Reads the inner clock and returns the smoothed estimate.
+    pub fn now_smoothed(&self) -> Millis {
+        let mut state = self.state.lock().unwrap();
+        self.sample(&mut state);
+        let average = Millis::new(state.average.unwrap_or_default().round() as u64);
+        state.last_reported = state.last_reported.max(average);
+        state.last_reported
+    }
+
+    /// Returns the weight of the newest reading in the moving average.
+    #[inline]
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    /// Returns a reference to the wrapped clock.
+    #[inline]
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    fn sample(&self, state: &mut SmoothingState) -> Millis {
+        let raw = self.inner.now();
+        let value = raw.absolute_milliseconds() as f64;
+        state.average = Some(match state.average {
+            None => value,
+            Some(average) => average + self.weight * (value - average),
+        });
+        raw
+    }
+}
+
+impl<C: MonotonicClock> MonotonicClock for DualClock<C> {
+    fn now(&self) -> Millis {
+        self.now_smoothed()
+    }
+}
@@ -0,0 +1,72 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MonotonicClock};
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// A `MonotonicClock` decorator that injects read failures, for resilience testing.
+///
+/// When configured with [`FaultyClock::fail_every`], every nth call to `now_checked()` returns
+/// `None`. `now()` goes through `now_checked()` and panics on an injected failure.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{FaultyClock, InstantMonotonicClock, MonotonicClock};
+/// let clock = FaultyClock::new(InstantMonotonicClock::new()).fail_every(2);
+/// assert!(clock.now_checked().is_some());
+/// assert!(clock.now_checked().is_none());
+/// ```
+#[derive(Debug)]
+pub struct FaultyClock<C> {
+    inner: C,
+    fail_every: Option<u64>,
+    calls: AtomicU64,
+}
+
+impl<C: MonotonicClock> FaultyClock<C> {
+    /// Wraps `inner` without injecting any failures.
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            fail_every: None,
+            calls: AtomicU64::new(0),
+        }
+    }
+
+    /// Makes every `n`th read fail.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn fail_every(mut self, n: u64) -> Self {
+        assert!(
+            n > 0,
+            "FaultyClock::fail_every requires a non-zero interval"
+        );
+        self.fail_every = Some(n);
+        self
+    }
+
+    /// Returns a reference to the wrapped clock.
+    #[inline]
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: MonotonicClock> MonotonicClock for FaultyClock<C> {
+    fn now(&self) -> Millis {
+        self.now_checked()
+            .expect("FaultyClock injected a clock failure")
+    }
+
+    fn now_checked(&self) -> Option<Millis> {
+        let call = self.calls.fetch_add(1, Ordering::Relaxed) + 1;
+        match self.fail_every {
+            Some(n) if call.is_multiple_of(n) => None,
+            _ => self.inner.now_checked(),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+/// Rounds half away from zero, like `f64::round`, which is not available in `core`.
+pub(crate) fn round(value: f64) -> f64 {
+    // From 2^52 and up, every f64 is already an integer.
+    if value.is_nan() || value.abs() >= 4_503_599_627_370_496.0 {
+        return value;
+    }
+    let truncated = value as i64 as f64;
+    let fraction = value - truncated;
+    if fraction >= 0.5 {
+        truncated + 1.0
+    } else if fraction <= -0.5 {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+/// Returns the non-negative remainder of `value / rhs`, like `f64::rem_euclid`.
+pub(crate) fn rem_euclid(value: f64, rhs: f64) -> f64 {
+    let remainder = value % rhs;
+    if remainder < 0.0 {
+        remainder + rhs.abs()
+    } else {
+        remainder
+    }
+}
+
+/// Converts seconds to whole milliseconds, rounded to the nearest millisecond.
+///
+/// Shared by the float constructors so they all report the same errors.
+pub(crate) fn secs_to_millis(secs: f64) -> Result<u64, &'static str> {
+    if !secs.is_finite() {
+        return Err("must be a number");
+    }
+    if secs < 0.0 {
+        return Err("must be a positive value");
+    }
+    let millis = round(secs * 1000.0);
+    if millis >= u64::MAX as f64 {
+        return Err("value is too large");
+    }
+    Ok(millis as u64)
+}
@@ -0,0 +1,61 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MillisDuration, MonotonicClock};
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// A `MonotonicClock` driven by a frame counter, for deterministic simulations.
+///
+/// `now()` returns `frame * frame_duration`, and only advances when `advance_frame` is called.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{FrameClock, Millis, MillisDuration, MonotonicClock};
+/// let clock = FrameClock::new(MillisDuration::from_millis(16));
+/// clock.advance_frame();
+/// clock.advance_frame();
+/// assert_eq!(clock.now(), Millis::new(32));
+/// ```
+#[derive(Debug)]
+pub struct FrameClock {
+    frame: AtomicU64,
+    frame_duration: MillisDuration,
+}
+
+impl FrameClock {
+    /// Creates a clock at frame zero.
+    pub fn new(frame_duration: MillisDuration) -> Self {
+        Self {
+            frame: AtomicU64::new(0),
+            frame_duration,
+        }
+    }
+
+    /// Advances the clock by one frame.
+    pub fn advance_frame(&self) {
+        self.frame.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the current frame number.
+    pub fn frame(&self) -> u64 {
+        self.frame.load(Ordering::Relaxed)
+    }
+
+    /// Returns the duration of a single frame.
+    #[inline]
+    pub fn frame_duration(&self) -> MillisDuration {
+        self.frame_duration
+    }
+}
+
+impl MonotonicClock for FrameClock {
+    fn now(&self) -> Millis {
+        Millis::new(
+            self.frame()
+                .checked_mul(self.frame_duration.as_millis())
+                .expect("overflow on FrameClock::now"),
+        )
+    }
+}
@@ -0,0 +1,85 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MonotonicClock};
+use std::sync::Mutex;
+
+#[derive(Debug, Default)]
+struct FreezeState {
+    frozen_at: Option<Millis>,
+    paused_millis: u64,
+}
+
+/// A `MonotonicClock` decorator that can be frozen and thawed.
+///
+/// While frozen, `now()` keeps returning the value from the moment of freezing. When thawed, the
+/// clock resumes from that value; the time spent frozen is subtracted from all later readings,
+/// so there is no jump.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{FreezableClock, InstantMonotonicClock, MonotonicClock};
+/// let clock = FreezableClock::new(InstantMonotonicClock::new());
+/// clock.freeze();
+/// let frozen = clock.now();
+/// std::thread::sleep(std::time::Duration::from_millis(20));
+/// assert_eq!(clock.now(), frozen);
+/// clock.thaw();
+/// assert!(clock.now() >= frozen);
+/// ```
+#[derive(Debug)]
+pub struct FreezableClock<C> {
+    inner: C,
+    state: Mutex<FreezeState>,
+}
+
+impl<C: MonotonicClock> FreezableClock<C> {
+    /// Wraps `inner` in a clock that is initially running.
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            state: Mutex::new(FreezeState::default()),
+        }
+    }
+
+    /// Freezes the clock at its current value. Does nothing if already frozen.
+    pub fn freeze(&self) {
+        let mut state = self.state.lock().unwrap();
+        if state.frozen_at.is_none() {
+            let now = self.inner.now().absolute_milliseconds() - state.paused_millis;
+            state.frozen_at = Some(Millis::new(now));
+        }
+    }
+
+    /// Resumes the clock from the frozen value. Does nothing if not frozen.
+    pub fn thaw(&self) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(frozen_at) = state.frozen_at.take() {
+            state.paused_millis =
+                self.inner.now().absolute_milliseconds() - frozen_at.absolute_milliseconds();
+        }
+    }
+
+    /// Returns `true` if the clock is currently frozen.
+    pub fn is_frozen(&self) -> bool {
+        self.state.lock().unwrap().frozen_at.is_some()
+    }
+
+    /// Returns a reference to the wrapped clock.
+    #[inline]
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: MonotonicClock> MonotonicClock for FreezableClock<C> {
+    fn now(&self) -> Millis {
+        let state = self.state.lock().unwrap();
+        match state.frozen_at {
+            Some(frozen_at) => frozen_at,
+            None => Millis::new(self.inner.now().absolute_milliseconds() - state.paused_millis),
+        }
+    }
+}
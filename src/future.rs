@@ -0,0 +1,39 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{MillisDuration, MonotonicClock};
+use core::future::Future;
+
+/// Extends every `MonotonicClock` with helpers for measuring futures.
+///
+/// Only available with the `async` feature.
+pub trait MonotonicClockFutureExt: MonotonicClock {
+    /// Awaits `future` and returns its output together with the time it took to complete.
+    ///
+    /// The clock is read once before the first poll and once after the future has completed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{InstantMonotonicClock, MonotonicClockFutureExt};
+    /// async fn measure() {
+    ///     let clock = InstantMonotonicClock::new();
+    ///     let (value, elapsed) = clock.time_future(async { 42 }).await;
+    ///     assert_eq!(value, 42);
+    ///     println!("took {elapsed}");
+    /// }
+    /// ```
+    fn time_future<'a, F: Future + 'a>(
+        &'a self,
+        future: F,
+    ) -> impl Future<Output = (F::Output, MillisDuration)> + 'a {
+        async move {
+            let start = self.now();
+            let output = future.await;
+            (output, self.now() - start)
+        }
+    }
+}
+
+impl<C: MonotonicClock + ?Sized> MonotonicClockFutureExt for C {}
@@ -0,0 +1,97 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{InstantMonotonicClock, Millis, MillisDuration, MonotonicClock};
+use std::sync::Mutex;
+
+#[derive(Debug)]
+struct InterpolationState {
+    coarse: Option<Millis>,
+    fine_at_coarse: Millis,
+    last_reported: Millis,
+}
+
+/// A `MonotonicClock` decorator that smooths a coarse clock using a fine-grained one.
+///
+/// Whenever the coarse clock changes value, the fine clock reading is remembered. Subsequent
+/// readings report the coarse value plus the fine time elapsed since that change, capped at
+/// `coarse_period` so the estimate never runs more than one coarse tick ahead. Reported values
+/// never decrease, even if the next coarse value is lower than an interpolated estimate.
+///
+/// The result is accurate to about the coarse clock's own update jitter, while being smooth
+/// between updates. If the coarse clock stalls for longer than `coarse_period`, the reported
+/// time holds at the cap until the next coarse update.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{InstantMonotonicClock, InterpolatingClock, MillisDuration, MonotonicClock};
+/// let clock = InterpolatingClock::new(InstantMonotonicClock::new(), MillisDuration::from_millis(10));
+/// let first = clock.now();
+/// assert!(clock.now() >= first);
+/// ```
+#[derive(Debug)]
+pub struct InterpolatingClock<C, F = InstantMonotonicClock> {
+    inner: C,
+    fine: F,
+    coarse_period: MillisDuration,
+    state: Mutex<InterpolationState>,
+}
+
+impl<C: MonotonicClock> InterpolatingClock<C> {
+    /// Interpolates `inner`, which updates every `coarse_period`, using an `InstantMonotonicClock`.
+    pub fn new(inner: C, coarse_period: MillisDuration) -> Self {
+        Self::with_fine_clock(inner, InstantMonotonicClock::new(), coarse_period)
+    }
+}
+
+impl<C: MonotonicClock, F: MonotonicClock> InterpolatingClock<C, F> {
+    /// Interpolates `inner`, which updates every `coarse_period`, using the provided `fine` clock.
+    pub fn with_fine_clock(inner: C, fine: F, coarse_period: MillisDuration) -> Self {
+        Self {
+            inner,
+            fine,
+            coarse_period,
+            state: Mutex::new(InterpolationState {
+                coarse: None,
+                fine_at_coarse: Millis::new(0),
+                last_reported: Millis::new(0),
+            }),
+        }
+    }
+
+    /// Returns a reference to the wrapped coarse clock.
+    #[inline]
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Returns a reference to the fine clock used for interpolation.
+    #[inline]
+    pub fn fine(&self) -> &F {
+        &self.fine
+    }
+}
+
+impl<C: MonotonicClock, F: MonotonicClock> MonotonicClock for InterpolatingClock<C, F> {
+    fn now(&self) -> Millis {
+        let coarse = self.inner.now();
+        let fine = self.fine.now();
+        let mut state = self.state.lock().unwrap();
+
+        if state.coarse != Some(coarse) {
+            state.coarse = Some(coarse);
+            state.fine_at_coarse = fine;
+        }
+
+        let since_coarse = fine
+            .checked_duration_since_ms(state.fine_at_coarse)
+            .unwrap_or(MillisDuration::from_millis(0))
+            .min(self.coarse_period);
+        let estimate = coarse + since_coarse;
+
+        state.last_reported = state.last_reported.max(estimate);
+        state.last_reported
+    }
+}
@@ -0,0 +1,49 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MillisDuration, MonotonicClock};
+use core::cell::Cell;
+
+/// Measures the time between consecutive calls to [`IntervalTimer::lap`].
+///
+/// The marker is kept in a `Cell`, so laps only need a shared reference. Since `Cell` is not
+/// `Sync`, a timer can not be shared between threads.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{IntervalTimer, ManualClock, Millis, MillisDuration};
+/// let clock = ManualClock::new(Millis::new(0));
+/// let timer = IntervalTimer::new(&clock);
+/// clock.advance(MillisDuration::from_millis(16));
+/// assert_eq!(timer.lap(&clock), MillisDuration::from_millis(16));
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntervalTimer {
+    marker: Cell<Millis>,
+}
+
+impl IntervalTimer {
+    /// Creates a timer whose first lap is measured from the current reading of `clock`.
+    pub fn new(clock: &impl MonotonicClock) -> Self {
+        Self {
+            marker: Cell::new(clock.now()),
+        }
+    }
+
+    /// Returns the time since the previous lap, and starts the next one.
+    ///
+    /// Returns zero if the clock has gone backwards since the previous lap.
+    pub fn lap(&self, clock: &impl MonotonicClock) -> MillisDuration {
+        let now = clock.now();
+        now.checked_duration_since_ms(self.marker.replace(now))
+            .unwrap_or_default()
+    }
+
+    /// Returns the time the current lap started.
+    #[inline]
+    pub fn marker(&self) -> Millis {
+        self.marker.get()
+    }
+}
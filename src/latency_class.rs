@@ -0,0 +1,21 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+/// A coarse health bucket for a latency, as returned by
+/// [`MillisDuration::classify`](crate::MillisDuration::classify) and
+/// [`MillisDuration::classify_with`](crate::MillisDuration::classify_with).
+///
+/// The variants are ordered from best to worst, so they can be compared directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LatencyClass {
+    /// Up to and including the fast threshold, which defaults to half of the normal threshold.
+    Fast,
+    /// Above the fast threshold, up to and including the normal threshold.
+    Normal,
+    /// Above the normal threshold, up to and including the slow threshold.
+    Slow,
+    /// Above the slow threshold.
+    Critical,
+}
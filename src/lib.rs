@@ -2,17 +2,157 @@
  * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
  * Licensed under the MIT License. See LICENSE in the project root for license information.
  */
+#![no_std]
+#![cfg_attr(feature = "step", feature(step_trait))]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+mod active_time;
+#[cfg(feature = "std")]
+mod clamped_step;
+#[cfg(target_has_atomic = "64")]
+mod counting;
+#[cfg(target_has_atomic = "64")]
+mod debug;
+#[cfg(feature = "defmt")]
+mod defmt_impl;
+mod drift;
+#[cfg(feature = "std")]
+mod dual;
+#[cfg(target_has_atomic = "64")]
+mod faulty;
+mod float;
+#[cfg(target_has_atomic = "64")]
+mod frame;
+#[cfg(feature = "std")]
+mod freezable;
+#[cfg(feature = "async")]
+mod future;
+#[cfg(feature = "std")]
+mod interpolating;
+mod interval;
+mod latency_class;
+mod low_tracker;
+mod lower_error;
+mod macros;
+#[cfg(target_has_atomic = "64")]
+mod manual;
+mod mapping;
+mod micros;
+#[cfg(target_has_atomic = "64")]
+mod min_resolution;
+mod moving_average;
+mod offset;
+mod peer;
+mod phase;
+mod pid;
+mod rate;
+#[cfg(feature = "std")]
+mod recording;
+mod ring;
+mod scaled;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod signed;
+#[cfg(feature = "step")]
+mod step;
+mod stopwatch;
+#[cfg(feature = "std")]
+mod system_time;
+#[cfg(feature = "time")]
+mod time_crate;
+mod time_id;
+mod timeout_guard;
+mod varint;
+#[cfg(feature = "std")]
+mod wall_anchored;
+#[cfg(feature = "std")]
 pub mod wasm;
+#[cfg(feature = "tokio")]
+mod watch;
 
-use std::fmt;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
-use std::time::{Duration, Instant};
+#[cfg(feature = "std")]
+pub use active_time::ActiveTimeClock;
+#[cfg(feature = "std")]
+pub use clamped_step::ClampedStepClock;
+#[cfg(target_has_atomic = "64")]
+pub use counting::CountingClock;
+#[cfg(target_has_atomic = "64")]
+pub use debug::DebugMonotonicClock;
+pub use drift::DriftEstimator;
+#[cfg(feature = "std")]
+pub use dual::DualClock;
+#[cfg(target_has_atomic = "64")]
+pub use faulty::FaultyClock;
+#[cfg(target_has_atomic = "64")]
+pub use frame::FrameClock;
+#[cfg(feature = "std")]
+pub use freezable::FreezableClock;
+#[cfg(feature = "async")]
+pub use future::MonotonicClockFutureExt;
+#[cfg(feature = "std")]
+pub use interpolating::InterpolatingClock;
+pub use interval::IntervalTimer;
+pub use latency_class::LatencyClass;
+pub use low_tracker::LowTracker;
+pub use lower_error::FromLowerError;
+#[cfg(target_has_atomic = "64")]
+pub use manual::ManualClock;
+pub use mapping::ClockMapping;
+pub use micros::{Micros, MicrosDuration};
+#[cfg(target_has_atomic = "64")]
+pub use min_resolution::MinResolutionClock;
+pub use moving_average::MovingAverageDuration;
+pub use offset::OffsetClock;
+pub use peer::PeerClockTracker;
+pub use phase::PhaseAccumulator;
+pub use pid::PidTimeIntegrator;
+pub use rate::Rate;
+#[cfg(feature = "std")]
+pub use recording::{PlaybackClock, RecordingClock};
+pub use ring::TimestampRing;
+pub use scaled::ScaledClock;
+pub use signed::SignedMillisDuration;
+pub use stopwatch::Stopwatch;
+#[cfg(feature = "std")]
+pub use system_time::SystemTimeMonotonicClock;
+#[cfg(feature = "time")]
+pub use time_crate::TimeCrateClock;
+pub use time_id::TimeIdGenerator;
+pub use timeout_guard::TimeoutGuard;
+#[cfg(feature = "std")]
+pub use wall_anchored::WallAnchoredClock;
+#[cfg(feature = "tokio")]
+pub use watch::WatchClock;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use core::str::FromStr;
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::Instant;
 
 /// Represents a monotonic absolute timestamp with millisecond resolution.
 ///
 /// This struct encapsulates a `u64` value representing the number of milliseconds since a
 /// implementation specific epoch.
+///
+/// With the `serde` feature, it serializes transparently as the underlying `u64`, so
+/// `Millis::new(1000)` becomes `1000`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(compare(PartialEq), derive(Debug))
+)]
 pub struct Millis(u64);
 
 impl Millis {
@@ -75,7 +215,11 @@ impl Millis {
     /// # Returns
     ///
     /// * `Some(Millis)` - The reconstructed monotonic timestamp if the difference is within 3000 milliseconds.
-    /// * `None` - If the difference between `now` and the reconstructed time exceeds 3000 milliseconds.
+    /// * `None` - If the difference between `now` and the reconstructed time exceeds 3000 milliseconds,
+    ///   or if the lower bits wrapped but `self` is too close to the epoch for that to be possible.
+    ///   Earlier versions returned a timestamp near `u64::MAX` in the latter case.
+    ///
+    /// Use [`Millis::try_from_lower`] to tell the two `None` cases apart.
     ///
     /// # Examples
     ///
@@ -87,24 +231,129 @@ impl Millis {
     /// assert_eq!(reconstructed, current);
     /// ```
     pub fn from_lower(&self, lower_bits: MillisLow16) -> Option<Millis> {
-        let now_bits = (self.0 & 0xffff) as u16;
+        self.from_lower_within(lower_bits, MillisDuration::from_millis(3000))
+    }
+
+    /// Reconstructs the full monotonic timestamp from the current time and lower bits, accepting
+    /// timestamps up to `max_skew` before `self`.
+    ///
+    /// Like [`Millis::from_lower`], which uses a fixed 3000 ms threshold.
+    ///
+    /// # Arguments
+    ///
+    /// * `lower_bits` - The lower 16 bits of a previously recorded timestamp.
+    /// * `max_skew` - The maximum age of the reconstructed timestamp relative to `self`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Millis)` - The reconstructed monotonic timestamp if it is at most `max_skew` before `self`.
+    /// * `None` - If the difference between `self` and the reconstructed time exceeds `max_skew`,
+    ///   or if the lower bits wrapped but `self` is too close to the epoch for that to be possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{Millis, MillisDuration};
+    /// let sent = Millis::new(10_000);
+    /// let now = Millis::new(10_500);
+    /// let max_skew = MillisDuration::from_millis(200);
+    /// assert_eq!(now.from_lower_within(sent.to_lower(), max_skew), None);
+    /// ```
+    pub fn from_lower_within(
+        &self,
+        lower_bits: MillisLow16,
+        max_skew: MillisDuration,
+    ) -> Option<Millis> {
+        Self::reconstruct_from_lower(*self, lower_bits, max_skew)
+    }
+
+    /// Reconstructs a full monotonic timestamp from its lower bits, relative to `now`.
+    ///
+    /// If the lower bits indicate a wrap-around, adjusts the timestamp accordingly.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time that the lower bits were recorded shortly before.
+    /// * `lower_bits` - The lower 16 bits of a previously recorded timestamp.
+    /// * `window` - The maximum age of the reconstructed timestamp relative to `now`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Millis)` - The reconstructed monotonic timestamp if it is at most `window` before `now`.
+    /// * `None` - If the difference between `now` and the reconstructed time exceeds `window`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{Millis, MillisDuration};
+    /// let sent = Millis::new(0x0001_fff0);
+    /// let now = Millis::new(0x0002_0010);
+    /// let window = MillisDuration::from_millis(100);
+    /// assert_eq!(Millis::reconstruct_from_lower(now, sent.to_lower(), window), Some(sent));
+    /// ```
+    pub fn reconstruct_from_lower(
+        now: Millis,
+        lower_bits: MillisLow16,
+        window: MillisDuration,
+    ) -> Option<Millis> {
+        Self::try_reconstruct_from_lower(now, lower_bits, window).ok()
+    }
+
+    /// Reconstructs the full monotonic timestamp like [`Millis::from_lower`], but reports why
+    /// reconstruction failed.
+    ///
+    /// # Errors
+    ///
+    /// * [`FromLowerError::SkewExceeded`] - If the reconstructed time is more than 3000
+    ///   milliseconds before `self`. The error carries the actual difference.
+    /// * [`FromLowerError::BeforeEpoch`] - If the reconstructed time would be before the epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{FromLowerError, Millis, MillisDuration};
+    /// let sent = Millis::new(10_000);
+    /// let now = Millis::new(14_000);
+    /// assert_eq!(
+    ///     now.try_from_lower(sent.to_lower()),
+    ///     Err(FromLowerError::SkewExceeded {
+    ///         diff: MillisDuration::from_millis(4000),
+    ///         threshold: MillisDuration::from_millis(3000),
+    ///     })
+    /// );
+    /// ```
+    pub fn try_from_lower(&self, lower_bits: MillisLow16) -> Result<Millis, FromLowerError> {
+        Self::try_reconstruct_from_lower(*self, lower_bits, MillisDuration::from_millis(3000))
+    }
+
+    fn try_reconstruct_from_lower(
+        now: Millis,
+        lower_bits: MillisLow16,
+        window: MillisDuration,
+    ) -> Result<Millis, FromLowerError> {
+        let now_bits = (now.0 & 0xffff) as u16;
         let received_lower_bits = lower_bits;
-        let top: u64 = self.0 & 0xffffffffffff0000;
+        let top: u64 = now.0 & 0xffffffffffff0000;
 
         let mut received_monotonic = top | (received_lower_bits as u64);
 
         // Adjust for wrap-around if lower bits have wrapped
         if received_lower_bits > now_bits {
-            received_monotonic = received_monotonic.wrapping_sub(0x10000);
+            received_monotonic = received_monotonic
+                .checked_sub(0x10000)
+                .ok_or(FromLowerError::BeforeEpoch)?;
         }
 
-        let diff = self.0.wrapping_sub(received_monotonic);
+        let diff = now.0 - received_monotonic;
 
-        if diff > 3000 {
-            return None;
+        if diff > window.0 {
+            return Err(FromLowerError::SkewExceeded {
+                diff: MillisDuration::from_millis(diff),
+                threshold: window,
+            });
         }
 
-        Some(Millis::new(received_monotonic))
+        Ok(Millis::new(received_monotonic))
     }
 
     /// Calculates the duration since another `Millis`.
@@ -192,131 +441,1537 @@ impl Millis {
         }
     }
 
-    /// Calculates the duration since another `Millis`, panicking if `self` is earlier.
+    /// Adds a duration to the timestamp, returning `None` if the result overflows `u64`.
+    ///
+    /// Prefer this over `+` when the duration comes from outside, such as a scheduled delay,
+    /// since `+` panics on overflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The duration to add.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{Millis, MillisDuration};
+    /// let timestamp = Millis::new(1000);
+    /// assert_eq!(timestamp.checked_add(MillisDuration::from_millis(500)), Some(Millis::new(1500)));
+    /// assert_eq!(Millis::new(u64::MAX).checked_add(MillisDuration::from_millis(1)), None);
+    /// ```
+    pub fn checked_add(self, duration: MillisDuration) -> Option<Millis> {
+        self.0.checked_add(duration.0).map(Self)
+    }
+
+    /// Adds a duration to the timestamp, clamping at `u64::MAX` instead of overflowing.
+    ///
+    /// Unlike the `+` operator, this never panics.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The duration to add.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{Millis, MillisDuration};
+    /// let timestamp = Millis::new(u64::MAX - 1);
+    /// assert_eq!(timestamp.saturating_add(MillisDuration::from_millis(10)), Millis::new(u64::MAX));
+    /// ```
+    pub fn saturating_add(self, duration: MillisDuration) -> Millis {
+        Self(self.0.saturating_add(duration.0))
+    }
+
+    /// Subtracts a duration from the timestamp, clamping at zero instead of underflowing.
+    ///
+    /// Unlike the `-` operator, this never panics.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The duration to subtract.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{Millis, MillisDuration};
+    /// let timestamp = Millis::new(5);
+    /// assert_eq!(timestamp.saturating_sub(MillisDuration::from_millis(10)), Millis::new(0));
+    /// ```
+    pub fn saturating_sub(self, duration: MillisDuration) -> Millis {
+        Self(self.0.saturating_sub(duration.0))
+    }
+
+    /// Calculates the duration since another `Millis`, panicking if `self` is earlier.
+    ///
+    /// # Arguments
+    ///
+    /// * `earlier` - The earlier monotonic timestamp.
+    ///
+    /// # Returns
+    ///
+    /// A `MillisDuration` representing the elapsed time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is earlier than `earlier`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// let start = Millis::new(1000);
+    /// let end = Millis::new(5000);
+    /// let duration = end.duration_since_ms(start);
+    /// assert_eq!(duration.as_millis(), 4000);
+    /// ```
+    pub fn duration_since_ms(&self, earlier: Millis) -> MillisDuration {
+        self.checked_duration_since_ms(earlier)
+            .expect("Millis::duration_since_ms called with a later timestamp")
+    }
+
+    /// Delta-encodes a list of timestamps for compact serialization.
+    ///
+    /// Returns the first timestamp as the base and, for each timestamp, its difference from the
+    /// previous one (the first delta is always zero). An empty slice encodes to a zero base with
+    /// no deltas.
+    ///
+    /// Sorted input produces small deltas. Unsorted input is still supported, since the deltas
+    /// are wrapping differences that [`Millis::delta_decode`] restores exactly, but every
+    /// backwards step encodes to a value close to `u64::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// let timestamps = [Millis::new(1000), Millis::new(1016), Millis::new(1033)];
+    /// let (base, deltas) = Millis::delta_encode(&timestamps);
+    /// assert_eq!(base, Millis::new(1000));
+    /// assert_eq!(deltas, vec![0, 16, 17]);
+    /// ```
+    pub fn delta_encode(timestamps: &[Millis]) -> (Millis, Vec<u64>) {
+        let base = timestamps.first().copied().unwrap_or(Millis::new(0));
+        let mut previous = base.0;
+        let deltas = timestamps
+            .iter()
+            .map(|timestamp| {
+                let delta = timestamp.0.wrapping_sub(previous);
+                previous = timestamp.0;
+                delta
+            })
+            .collect();
+        (base, deltas)
+    }
+
+    /// Restores the timestamps produced by [`Millis::delta_encode`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// let timestamps = Millis::delta_decode(Millis::new(1000), &[0, 16, 17]);
+    /// assert_eq!(timestamps, vec![Millis::new(1000), Millis::new(1016), Millis::new(1033)]);
+    /// ```
+    pub fn delta_decode(base: Millis, deltas: &[u64]) -> Vec<Millis> {
+        let mut current = base.0;
+        deltas
+            .iter()
+            .map(|delta| {
+                current = current.wrapping_add(*delta);
+                Millis::new(current)
+            })
+            .collect()
+    }
+
+    /// Converts the timestamp into a tick index, flooring any partial tick.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tick_duration` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{Millis, MillisDuration};
+    /// let tick_duration = MillisDuration::from_millis(16);
+    /// assert_eq!(Millis::new(100).to_tick(tick_duration), 6);
+    /// ```
+    pub fn to_tick(&self, tick_duration: MillisDuration) -> u64 {
+        self.0
+            .checked_div(tick_duration.0)
+            .expect("Millis::to_tick called with a zero tick duration")
+    }
+
+    /// Returns the timestamp at the start of tick index `tick`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tick_duration` is zero or if the timestamp overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{Millis, MillisDuration};
+    /// let tick_duration = MillisDuration::from_millis(16);
+    /// assert_eq!(Millis::from_tick(6, tick_duration), Millis::new(96));
+    /// ```
+    pub fn from_tick(tick: u64, tick_duration: MillisDuration) -> Millis {
+        assert!(
+            tick_duration.0 != 0,
+            "Millis::from_tick called with a zero tick duration"
+        );
+        Millis::new(
+            tick.checked_mul(tick_duration.0)
+                .expect("overflow on Millis::from_tick"),
+        )
+    }
+
+    /// Appends the timestamp to `out` as a LEB128 varint.
+    ///
+    /// Values below 128 take a single byte and values below 16384 take two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// let mut out = Vec::new();
+    /// Millis::new(300).write_varint(&mut out);
+    /// assert_eq!(out, vec![0xac, 0x02]);
+    /// ```
+    pub fn write_varint(&self, out: &mut Vec<u8>) {
+        varint::write_u64(self.0, out);
+    }
+
+    /// Reads a timestamp written by [`Millis::write_varint`].
+    ///
+    /// # Returns
+    ///
+    /// * `Some((Millis, usize))` - The timestamp and the number of bytes consumed.
+    /// * `None` - If the input is truncated or the value does not fit in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// assert_eq!(Millis::read_varint(&[0xac, 0x02, 0xff]), Some((Millis::new(300), 2)));
+    /// ```
+    pub fn read_varint(bytes: &[u8]) -> Option<(Millis, usize)> {
+        varint::read_u64(bytes).map(|(value, length)| (Millis::new(value), length))
+    }
+
+    /// Extracts the lower `bits` bits from the timestamp.
+    ///
+    /// A generalization of [`Millis::to_lower`] for custom wire formats.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is greater than 64.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// let timestamp = Millis::new(0x12345678);
+    /// assert_eq!(timestamp.to_lower_bits(12), 0x678);
+    /// ```
+    pub fn to_lower_bits(&self, bits: u32) -> u64 {
+        self.0 & Self::lower_mask(bits)
+    }
+
+    /// Reconstructs a full timestamp from its lower `bits` bits, using `self` as the current time.
+    ///
+    /// If the lower bits indicate a wrap-around, adjusts the timestamp accordingly.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The lower `bits` bits of a previously recorded timestamp.
+    /// * `bits` - The number of bits in `value`.
+    /// * `window` - The maximum age of the reconstructed timestamp relative to `self`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Millis)` - The reconstructed timestamp if it is at most `window` before `self`.
+    /// * `None` - If `value` does not fit in `bits` bits, the reconstructed time would be before
+    ///   the epoch, or it is more than `window` before `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is greater than 64.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{Millis, MillisDuration};
+    /// let sent = Millis::new(0x1ff0);
+    /// let now = Millis::new(0x2010);
+    /// let window = MillisDuration::from_millis(100);
+    /// assert_eq!(now.from_lower_bits(sent.to_lower_bits(12), 12, window), Some(sent));
+    /// ```
+    pub fn from_lower_bits(&self, value: u64, bits: u32, window: MillisDuration) -> Option<Millis> {
+        let mask = Self::lower_mask(bits);
+        if value & !mask != 0 {
+            return None;
+        }
+
+        let mut received = (self.0 & !mask) | value;
+        if received > self.0 {
+            received = received.checked_sub(mask.checked_add(1)?)?;
+        }
+
+        if self.0 - received > window.0 {
+            return None;
+        }
+
+        Some(Millis::new(received))
+    }
+
+    fn lower_mask(bits: u32) -> u64 {
+        assert!(bits <= 64, "lower bits must be at most 64, got {bits}");
+        u64::MAX.checked_shr(64 - bits).unwrap_or(0)
+    }
+
+    /// Parses a decimal millisecond string and checks that it lies within `[min, max]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// let parsed = Millis::parse_validated("1500", Millis::new(0), Millis::new(10_000));
+    /// assert_eq!(parsed, Ok(Millis::new(1500)));
+    /// ```
+    pub fn parse_validated(s: &str, min: Millis, max: Millis) -> Result<Millis, &'static str> {
+        let millis = Millis::new(
+            s.trim()
+                .parse()
+                .map_err(|_| "expected a non-negative integer millisecond value")?,
+        );
+        if millis < min {
+            return Err("value is below the minimum");
+        }
+        if millis > max {
+            return Err("value is above the maximum");
+        }
+        Ok(millis)
+    }
+
+    /// Formats the time since the epoch as an ISO-8601 duration, such as `"PT1.5S"`.
+    ///
+    /// See [`MillisDuration::to_iso8601_duration`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// assert_eq!(Millis::new(1500).to_iso8601_duration(), "PT1.5S");
+    /// ```
+    pub fn to_iso8601_duration(&self) -> String {
+        MillisDuration::from_millis(self.0).to_iso8601_duration()
+    }
+
+    /// Returns the millisecond value as a string, without the `" ms"` suffix of `Display`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// assert_eq!(Millis::new(1_614_834_000).to_number_string(), "1614834000");
+    /// ```
+    pub fn to_number_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the earliest timestamp in `timestamps`, or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// let events = [Millis::new(300), Millis::new(100), Millis::new(200)];
+    /// assert_eq!(Millis::earliest(&events), Some(Millis::new(100)));
+    /// ```
+    pub fn earliest(timestamps: &[Millis]) -> Option<Millis> {
+        timestamps.iter().min().copied()
+    }
+
+    /// Returns the latest timestamp in `timestamps`, or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// let events = [Millis::new(300), Millis::new(100), Millis::new(200)];
+    /// assert_eq!(Millis::latest(&events), Some(Millis::new(300)));
+    /// ```
+    pub fn latest(timestamps: &[Millis]) -> Option<Millis> {
+        timestamps.iter().max().copied()
+    }
+
+    /// Creates a `Millis` from a number of seconds, rounded to the nearest millisecond.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `secs` is negative, NaN, infinite or too large for a `u64`
+    /// millisecond value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// assert_eq!(Millis::from_secs_f64(1.2345), Ok(Millis::new(1235)));
+    /// assert!(Millis::from_secs_f64(-1.0).is_err());
+    /// ```
+    pub fn from_secs_f64(secs: f64) -> Result<Millis, &'static str> {
+        float::secs_to_millis(secs).map(Millis::new)
+    }
+
+    /// Returns the lower 16 bits together with the number of completed 16-bit wraps.
+    ///
+    /// The wrap count is the upper bits divided by `0x10000`, truncated to a `u16`, so the pair
+    /// covers the lower 32 bits of the timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// let timestamp = Millis::new(0x0003_1234);
+    /// assert_eq!(timestamp.lower_with_wrap_count(), (0x1234, 3));
+    /// ```
+    pub const fn lower_with_wrap_count(&self) -> (MillisLow16, u16) {
+        (self.to_lower(), (self.0 >> 16) as u16)
+    }
+
+    /// Rebuilds a timestamp from the pair returned by [`Millis::lower_with_wrap_count`].
+    ///
+    /// Only the lower 32 bits can be recovered; timestamps at or beyond `0x1_0000_0000`
+    /// milliseconds (about 49.7 days) come back truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// assert_eq!(Millis::from_lower_with_wrap_count(0x1234, 3), Millis::new(0x0003_1234));
+    /// ```
+    pub const fn from_lower_with_wrap_count(lower: MillisLow16, wrap_count: u16) -> Millis {
+        Millis(((wrap_count as u64) << 16) | lower as u64)
+    }
+
+    /// Returns the index of the first position where two sequences of readings differ.
+    ///
+    /// If one sequence is a prefix of the other, the length of the shorter one is returned.
+    /// Returns `None` if the sequences are identical.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// let recorded = [10, 20, 30].map(Millis::new);
+    /// let replayed = [10, 21, 30].map(Millis::new);
+    /// assert_eq!(Millis::first_divergence(&recorded, &replayed), Some(1));
+    /// ```
+    pub fn first_divergence(a: &[Millis], b: &[Millis]) -> Option<usize> {
+        match a.iter().zip(b).position(|(left, right)| left != right) {
+            Some(index) => Some(index),
+            None if a.len() != b.len() => Some(a.len().min(b.len())),
+            None => None,
+        }
+    }
+
+    /// Adds a random jitter of up to `max_jitter` to the timestamp.
+    ///
+    /// Useful to spread out scheduled work across many nodes. The jitter is `rng() * max_jitter`,
+    /// truncated to whole milliseconds. `rng` should return a value in `[0, 1)`; values outside
+    /// of `[0, 1]` are clamped to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_jitter` - The largest jitter to add.
+    /// * `rng` - Returns a random fraction, making the jitter deterministic in tests.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows, like `+`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{Millis, MillisDuration};
+    /// let next_fire = Millis::new(1000).with_jitter(MillisDuration::from_millis(200), &mut || 0.25);
+    /// assert_eq!(next_fire, Millis::new(1050));
+    /// ```
+    pub fn with_jitter(&self, max_jitter: MillisDuration, rng: &mut impl FnMut() -> f32) -> Millis {
+        let fraction = f64::from(rng().clamp(0.0, 1.0));
+        let jitter = (fraction * max_jitter.as_millis() as f64) as u64;
+        *self + MillisDuration::from_millis(jitter)
+    }
+
+    /// Returns the absolute difference between two timestamps, regardless of their order.
+    ///
+    /// Unlike `-`, this never panics, which makes it convenient for skew checks where only the
+    /// magnitude matters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{Millis, MillisDuration};
+    /// let local = Millis::new(1000);
+    /// let remote = Millis::new(1250);
+    /// assert_eq!(local.abs_diff(remote), MillisDuration::from_millis(250));
+    /// assert_eq!(remote.abs_diff(local), MillisDuration::from_millis(250));
+    /// ```
+    #[inline]
+    pub fn abs_diff(self, other: Millis) -> MillisDuration {
+        MillisDuration::from_millis(self.0.abs_diff(other.0))
+    }
+
+    /// Formats the time since the epoch as a zero-padded `"HH:MM:SS.mmm"` timecode.
+    ///
+    /// See [`MillisDuration::format_hms`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// assert_eq!(Millis::new(3_661_500).format_hms(), "01:01:01.500");
+    /// ```
+    pub fn format_hms(&self) -> String {
+        MillisDuration::from_millis(self.0).format_hms()
+    }
+
+    /// Returns the lower 48 bits of the timestamp as big-endian bytes.
+    ///
+    /// 48 bits of milliseconds cover about 8900 years, so this saves two bytes on the wire
+    /// compared to a full `u64` while rarely wrapping around.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// let timestamp = Millis::new(0x0102_0304_0506_0708);
+    /// assert_eq!(timestamp.to_lower48(), [0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+    /// ```
+    pub fn to_lower48(&self) -> [u8; 6] {
+        let bytes = self.to_lower_bits(48).to_be_bytes();
+        [bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7]]
+    }
+
+    /// Reconstructs a full timestamp from the bytes of [`Millis::to_lower48`], using `self` as
+    /// the current time.
+    ///
+    /// Works like [`Millis::from_lower`], but with a 48-bit window: the timestamp may be up to
+    /// 2^48 - 1 ms (about 8900 years) before `self`. It must not be after `self`, so the sender's
+    /// clock must not be ahead of the receiver's. A timestamp from the future is taken to be
+    /// from one 48-bit wrap-around earlier.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Millis)` - The reconstructed timestamp.
+    /// * `None` - If the reconstructed timestamp would be before the epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// let sent = Millis::new(0x1_0000_0000_0000 - 10);
+    /// let now = Millis::new(0x1_0000_0000_0000 + 5);
+    /// assert_eq!(now.from_lower48(sent.to_lower48()), Some(sent));
+    /// ```
+    pub fn from_lower48(&self, bytes: [u8; 6]) -> Option<Millis> {
+        let [b0, b1, b2, b3, b4, b5] = bytes;
+        let value = u64::from_be_bytes([0, 0, b0, b1, b2, b3, b4, b5]);
+        self.from_lower_bits(value, 48, MillisDuration(Self::lower_mask(48)))
+    }
+
+    /// Extracts the lower 32 bits from the timestamp.
+    ///
+    /// 32 bits wrap around every 2^32 ms, about 49.7 days, so the reconstruction in
+    /// [`Millis::from_lower32`] tolerates far longer delays than the 16-bit [`Millis::to_lower`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// assert_eq!(Millis::new(0x1234_5678_9abc).to_lower32(), 0x5678_9abc);
+    /// ```
+    pub const fn to_lower32(&self) -> u32 {
+        self.0 as u32
+    }
+
+    /// Reconstructs the full timestamp from its lower 32 bits, using `self` as the current time.
+    ///
+    /// Accepts timestamps up to 60 000 ms before `self`. Use [`Millis::from_lower32_within`] to
+    /// choose another threshold.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Millis)` - The reconstructed timestamp if it is at most 60 000 ms before `self`.
+    /// * `None` - Otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// let sent = Millis::new(0x1_0000_0000 - 20_000);
+    /// let now = Millis::new(0x1_0000_0000 + 10_000);
+    /// assert_eq!(now.from_lower32(sent.to_lower32()), Some(sent));
+    /// ```
+    pub fn from_lower32(&self, bits: u32) -> Option<Millis> {
+        self.from_lower32_within(bits, MillisDuration::from_millis(60_000))
+    }
+
+    /// Reconstructs the full timestamp from its lower 32 bits, accepting timestamps up to
+    /// `max_skew` before `self`.
+    ///
+    /// `max_skew` should be well below the 2^32 ms wrap-around period to be meaningful.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{Millis, MillisDuration};
+    /// let now = Millis::new(100_000);
+    /// let sent = Millis::new(70_000);
+    /// assert_eq!(now.from_lower32_within(sent.to_lower32(), MillisDuration::from_millis(30_000)), Some(sent));
+    /// assert_eq!(now.from_lower32_within(sent.to_lower32(), MillisDuration::from_millis(29_999)), None);
+    /// ```
+    pub fn from_lower32_within(&self, bits: u32, max_skew: MillisDuration) -> Option<Millis> {
+        self.from_lower_bits(u64::from(bits), 32, max_skew)
+    }
+
+    /// Returns the position of the timestamp within `[start, end]`, as a fraction in `[0, 1]`.
+    ///
+    /// Timestamps outside of the span are clamped to it. An empty span, where `start == end`,
+    /// returns `0.0`, as does a reversed span where `end` is before `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Millis;
+    /// let position = Millis::new(1250).normalized_in(Millis::new(1000), Millis::new(2000));
+    /// assert_eq!(position, 0.25);
+    /// ```
+    pub fn normalized_in(&self, start: Millis, end: Millis) -> f32 {
+        if end <= start {
+            return 0.0;
+        }
+        let offset = self.0.clamp(start.0, end.0) - start.0;
+        (offset as f64 / (end.0 - start.0) as f64) as f32
+    }
+}
+
+impl AddAssign<MillisDuration> for Millis {
+    fn add_assign(&mut self, other: MillisDuration) {
+        self.0 += other.0;
+    }
+}
+
+impl SubAssign<MillisDuration> for Millis {
+    fn sub_assign(&mut self, other: MillisDuration) {
+        self.0 -= other.0;
+    }
+}
+
+impl Add<MillisDuration> for Millis {
+    type Output = Self;
+
+    fn add(self, other: MillisDuration) -> Self::Output {
+        Self(self.0 + other.0)
+    }
+}
+
+impl Sub<MillisDuration> for Millis {
+    type Output = Self;
+
+    fn sub(self, other: MillisDuration) -> Self::Output {
+        Self(self.0 - other.0)
+    }
+}
+
+/// Represents the lower 16 bits of a timestamp in milliseconds.
+///
+/// This type alias is used for efficient serialization scenarios where only a subset of the
+/// timestamp is needed.
+pub type MillisLow16 = u16;
+
+/// Represents a duration in milliseconds.
+///
+/// With the `serde` feature, it serializes as the plain millisecond count. Enabling
+/// `serde_human` as well makes human-readable formats such as JSON use a string like `"4s"`,
+/// while compact formats keep the plain `u64`. Deserialization also accepts a float, rounded to
+/// the nearest millisecond.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(compare(PartialEq), derive(Debug))
+)]
+pub struct MillisDuration(u64);
+
+impl MillisDuration {
+    /// One second.
+    pub const SECOND: Self = Self(1000);
+
+    /// One minute.
+    pub const MINUTE: Self = Self(60 * 1000);
+
+    /// One hour.
+    pub const HOUR: Self = Self(60 * 60 * 1000);
+
+    /// One day.
+    pub const DAY: Self = Self(24 * 60 * 60 * 1000);
+
+    /// One frame at 60 Hz, truncated to whole milliseconds (16 ms).
+    pub const FRAME_60HZ: Self = Self(1000 / 60);
+
+    /// Creates a new `MillisDuration` instance from milliseconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `millis` - The duration in milliseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// let duration = MillisDuration::from_millis(4000);
+    /// ```
+    #[inline]
+    pub const fn from_millis(millis: u64) -> Self {
+        Self(millis)
+    }
+
+    /// Creates a new `MillisDuration` from a number of seconds.
+    /// Returns an error if the input is negative.
+    ///
+    /// `f32` only has about seven significant digits, so large values lose millisecond
+    /// precision. Prefer [`MillisDuration::from_secs_f64`] for those.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// let duration = MillisDuration::from_secs(2.5).unwrap();
+    /// assert_eq!(duration.as_millis(), 2500);
+    /// ```
+    #[inline]
+    pub fn from_secs(seconds: f32) -> Result<Self, &'static str> {
+        if seconds < 0.0 {
+            return Err("must be a positive value");
+        }
+        Ok(Self((seconds * 1000.0) as u64))
+    }
+
+    /// Creates a new `MillisDuration` from a number of seconds, rounded to the nearest
+    /// millisecond.
+    ///
+    /// The math is done in `f64`, so durations of days or more keep millisecond precision.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `seconds` is negative, NaN, infinite or too large for a `u64`
+    /// millisecond value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// let duration = MillisDuration::from_secs_f64(86_400.5).unwrap();
+    /// assert_eq!(duration.as_millis(), 86_400_500);
+    /// assert!(MillisDuration::from_secs_f64(f64::NAN).is_err());
+    /// ```
+    pub fn from_secs_f64(seconds: f64) -> Result<Self, &'static str> {
+        float::secs_to_millis(seconds).map(Self)
+    }
+
+    /// Returns the duration in milliseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// let duration = MillisDuration::from_millis(4000);
+    /// assert_eq!(duration.as_millis(), 4000);
+    /// ```
+    #[inline]
+    pub fn as_millis(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the duration in seconds as an `f32`.
+    ///
+    /// An `f32` only has about seven significant digits, so durations longer than a few hours
+    /// lose millisecond precision.
+    #[deprecated(note = "lossy for long durations, use `as_secs_f64` instead")]
+    pub fn as_secs(&self) -> f32 {
+        self.0 as f32 / 1000.0
+    }
+
+    /// Returns the duration in seconds as an `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// let duration = MillisDuration::from_millis(1500);
+    /// assert_eq!(duration.as_secs_f64(), 1.5);
+    /// ```
+    #[inline]
+    pub fn as_secs_f64(&self) -> f64 {
+        self.0 as f64 / 1000.0
+    }
+
+    /// Reads a duration from the environment variable `var`.
+    ///
+    /// The value is parsed with the `FromStr` implementation, so both `"1500"` and `"1500ms"`
+    /// are accepted.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(MillisDuration))` - If the variable is set and holds a valid duration.
+    /// * `Ok(None)` - If the variable is not set.
+    /// * `Err` - If the variable is not valid unicode or can not be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// assert_eq!(MillisDuration::from_env("MONOTONIC_TIME_RS_DOC_UNSET"), Ok(None));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_env(var: &str) -> Result<Option<Self>, &'static str> {
+        match std::env::var(var) {
+            Ok(value) => value.parse().map(Some),
+            Err(std::env::VarError::NotPresent) => Ok(None),
+            Err(std::env::VarError::NotUnicode(_)) => {
+                Err("environment variable is not valid unicode")
+            }
+        }
+    }
+
+    /// Linearly remaps this duration from the range `[in_min, in_max]` to `[out_min, out_max]`.
+    ///
+    /// Durations outside of the input range are clamped to it first, so the result always lies
+    /// between `out_min` and `out_max`. If `in_min` equals `in_max`, `out_min` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// let elapsed = MillisDuration::from_millis(250);
+    /// let alpha = elapsed.map_range(
+    ///     MillisDuration::from_millis(0),
+    ///     MillisDuration::from_millis(1000),
+    ///     0.0,
+    ///     1.0,
+    /// );
+    /// assert_eq!(alpha, 0.25);
+    /// ```
+    pub fn map_range(
+        &self,
+        in_min: MillisDuration,
+        in_max: MillisDuration,
+        out_min: f32,
+        out_max: f32,
+    ) -> f32 {
+        if in_min == in_max {
+            return out_min;
+        }
+        let (low, high) = if in_min < in_max {
+            (in_min.0, in_max.0)
+        } else {
+            (in_max.0, in_min.0)
+        };
+        let clamped = self.0.clamp(low, high);
+        let t = (clamped as f64 - in_min.0 as f64) / (in_max.0 as f64 - in_min.0 as f64);
+        (out_min as f64 + t * (out_max as f64 - out_min as f64)) as f32
+    }
+
+    /// Multiplies the duration by `factor`, returning `None` if the result is not representable.
+    ///
+    /// Unlike the `Mul<f32>` operator, this rejects negative, NaN and infinite factors as well as
+    /// products exceeding `u64::MAX` milliseconds. The product is computed in `f64` and then
+    /// truncated, while the operator computes in `f32`, so durations above 2^24 ms (about 4.6
+    /// hours) can give a more precise result here than `duration * factor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// let duration = MillisDuration::from_millis(800);
+    /// assert_eq!(duration.checked_mul_f32(1.5), Some(MillisDuration::from_millis(1200)));
+    /// assert_eq!(duration.checked_mul_f32(-1.0), None);
+    /// ```
+    pub fn checked_mul_f32(self, factor: f32) -> Option<MillisDuration> {
+        if !factor.is_finite() || factor < 0.0 {
+            return None;
+        }
+        let product = self.0 as f64 * factor as f64;
+        // `u64::MAX as f64` rounds up to 2^64, which itself is out of range.
+        if product >= u64::MAX as f64 {
+            return None;
+        }
+        Some(Self::from_millis(product as u64))
+    }
+
+    /// Returns the ratio of this duration to `total`, without clamping.
+    ///
+    /// This is float division of two durations. For the integer quotient, see
+    /// [`MillisDuration::div_duration_floor`] and [`MillisDuration::div_duration_ceil`].
+    /// `MillisDuration` deliberately has no `Div<MillisDuration>` operator, so the choice
+    /// between the two is always explicit.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(f64)` - The ratio, which may exceed `1.0`.
+    /// * `None` - If `total` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// let elapsed = MillisDuration::from_millis(1500);
+    /// assert_eq!(elapsed.ratio_of(MillisDuration::from_millis(1000)), Some(1.5));
+    /// ```
+    pub fn ratio_of(&self, total: MillisDuration) -> Option<f64> {
+        if total.0 == 0 {
+            None
+        } else {
+            Some(self.0 as f64 / total.0 as f64)
+        }
+    }
+
+    /// Returns `true` if this duration differs from `other` by at most `tolerance`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// let measured = MillisDuration::from_millis(1040);
+    /// assert!(measured.approx_eq(
+    ///     MillisDuration::from_millis(1000),
+    ///     MillisDuration::from_millis(100)
+    /// ));
+    /// ```
+    pub fn approx_eq(&self, other: MillisDuration, tolerance: MillisDuration) -> bool {
+        self.0.abs_diff(other.0) <= tolerance.0
+    }
+
+    /// Returns the duration in whole seconds, rounded to the nearest second.
+    ///
+    /// Half a second rounds up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// assert_eq!(MillisDuration::from_millis(1499).as_secs_rounded(), 1);
+    /// assert_eq!(MillisDuration::from_millis(1500).as_secs_rounded(), 2);
+    /// ```
+    #[inline]
+    pub fn as_secs_rounded(&self) -> u64 {
+        self.0 / 1000 + u64::from(self.0 % 1000 >= 500)
+    }
+
+    /// Returns the duration in whole seconds, truncating any partial second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// assert_eq!(MillisDuration::from_millis(1999).as_secs_floor(), 1);
+    /// ```
+    #[inline]
+    pub fn as_secs_floor(&self) -> u64 {
+        self.0 / 1000
+    }
+
+    /// Returns the duration in whole seconds, counting any partial second as a full one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// assert_eq!(MillisDuration::from_millis(1001).as_secs_ceil(), 2);
+    /// ```
+    #[inline]
+    pub fn as_secs_ceil(&self) -> u64 {
+        self.0.div_ceil(1000)
+    }
+
+    /// Returns the duration in minutes as an `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// assert_eq!(MillisDuration::from_millis(90_000).as_minutes_f64(), 1.5);
+    /// ```
+    #[inline]
+    pub fn as_minutes_f64(&self) -> f64 {
+        self.0 as f64 / 60_000.0
+    }
+
+    /// Returns the duration in hours as an `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// assert_eq!(MillisDuration::from_millis(5_400_000).as_hours_f64(), 1.5);
+    /// ```
+    #[inline]
+    pub fn as_hours_f64(&self) -> f64 {
+        self.0 as f64 / 3_600_000.0
+    }
+
+    /// Appends the duration to `out` as a LEB128 varint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// let mut out = Vec::new();
+    /// MillisDuration::from_millis(16).write_varint(&mut out);
+    /// assert_eq!(out, vec![16]);
+    /// ```
+    pub fn write_varint(&self, out: &mut Vec<u8>) {
+        varint::write_u64(self.0, out);
+    }
+
+    /// Reads a duration written by [`MillisDuration::write_varint`].
+    ///
+    /// # Returns
+    ///
+    /// * `Some((MillisDuration, usize))` - The duration and the number of bytes consumed.
+    /// * `None` - If the input is truncated or the value does not fit in a `u64`.
+    pub fn read_varint(bytes: &[u8]) -> Option<(MillisDuration, usize)> {
+        varint::read_u64(bytes).map(|(value, length)| (MillisDuration::from_millis(value), length))
+    }
+
+    /// Sums the durations from `iter`, returning `None` if the total overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// let durations = [MillisDuration::from_millis(100), MillisDuration::from_millis(250)];
+    /// assert_eq!(
+    ///     MillisDuration::checked_sum(durations.into_iter()),
+    ///     Some(MillisDuration::from_millis(350))
+    /// );
+    /// ```
+    pub fn checked_sum<I: Iterator<Item = MillisDuration>>(mut iter: I) -> Option<MillisDuration> {
+        iter.try_fold(Self::from_millis(0), |total, duration| {
+            total.0.checked_add(duration.0).map(Self::from_millis)
+        })
+    }
+
+    /// Computes the harmonic mean of `durations`, rounded to the nearest millisecond.
+    ///
+    /// This is the correct average when the durations are periods of a rate, such as frame
+    /// times: the harmonic mean of the frame times is the period of the average frame rate.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(MillisDuration)` - The harmonic mean.
+    /// * `None` - If `durations` is empty or contains a zero duration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// let frame_times = [MillisDuration::from_millis(10), MillisDuration::from_millis(40)];
+    /// assert_eq!(
+    ///     MillisDuration::harmonic_mean(&frame_times),
+    ///     Some(MillisDuration::from_millis(16))
+    /// );
+    /// ```
+    pub fn harmonic_mean(durations: &[MillisDuration]) -> Option<MillisDuration> {
+        if durations.is_empty() || durations.iter().any(|duration| duration.0 == 0) {
+            return None;
+        }
+        let reciprocal_sum: f64 = durations
+            .iter()
+            .map(|duration| 1.0 / duration.0 as f64)
+            .sum();
+        Some(Self::from_millis(
+            float::round(durations.len() as f64 / reciprocal_sum) as u64,
+        ))
+    }
+
+    /// Formats the duration as an ISO-8601 duration, such as `"PT1M30.5S"`.
+    ///
+    /// Only hours, minutes and seconds are used, so long durations are expressed in hours.
+    /// Zero components are omitted and a zero duration is formatted as `"PT0S"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// assert_eq!(MillisDuration::from_millis(1500).to_iso8601_duration(), "PT1.5S");
+    /// assert_eq!(MillisDuration::from_millis(90_000).to_iso8601_duration(), "PT1M30S");
+    /// ```
+    pub fn to_iso8601_duration(&self) -> String {
+        let hours = self.0 / 3_600_000;
+        let minutes = self.0 / 60_000 % 60;
+        let seconds = self.0 / 1000 % 60;
+        let millis = self.0 % 1000;
+
+        let mut iso = String::from("PT");
+        if hours > 0 {
+            iso += &format!("{hours}H");
+        }
+        if minutes > 0 {
+            iso += &format!("{minutes}M");
+        }
+        if millis > 0 {
+            let fraction = format!("{millis:03}");
+            iso += &format!("{seconds}.{}S", fraction.trim_end_matches('0'));
+        } else if seconds > 0 || self.0 == 0 {
+            iso += &format!("{seconds}S");
+        }
+        iso
+    }
+
+    /// Splits the duration into `n` parts that sum exactly to the original.
+    ///
+    /// The remainder is distributed one millisecond at a time over the first parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// let parts = MillisDuration::from_millis(10).split_evenly(3);
+    /// assert_eq!(parts, [4, 3, 3].map(MillisDuration::from_millis).to_vec());
+    /// ```
+    pub fn split_evenly(self, n: u32) -> Vec<MillisDuration> {
+        assert!(n > 0, "MillisDuration::split_evenly called with zero parts");
+        let n = n as u64;
+        let base = self.0 / n;
+        let remainder = self.0 % n;
+        (0..n)
+            .map(|index| Self::from_millis(base + u64::from(index < remainder)))
+            .collect()
+    }
+
+    /// Returns the millisecond count as a string, without the `" ms"` suffix of `Display`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// assert_eq!(MillisDuration::from_millis(4000).to_number_string(), "4000");
+    /// ```
+    pub fn to_number_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the label of the shorter of two labeled durations.
+    ///
+    /// If both durations are equal, the label of `a` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// let faster = MillisDuration::faster_of(
+    ///     ("vec", MillisDuration::from_millis(12)),
+    ///     ("map", MillisDuration::from_millis(30)),
+    /// );
+    /// assert_eq!(faster, "vec");
+    /// ```
+    pub fn faster_of<'a>(a: (&'a str, MillisDuration), b: (&'a str, MillisDuration)) -> &'a str {
+        if b.1 < a.1 {
+            b.0
+        } else {
+            a.0
+        }
+    }
+
+    /// Generates a schedule of durations, starting at `init` and stepping with `step_fn`.
+    ///
+    /// Each following duration is `step_fn` applied to the previous one; the schedule ends when
+    /// `step_fn` returns `None`. This covers backoff sequences, tick generators and the like.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// let backoff: Vec<u64> = MillisDuration::schedule(MillisDuration::from_millis(100), |previous| {
+    ///     (previous.as_millis() < 800).then(|| previous * 2)
+    /// })
+    /// .map(|duration| duration.as_millis())
+    /// .collect();
+    /// assert_eq!(backoff, vec![100, 200, 400, 800]);
+    /// ```
+    pub fn schedule<F>(init: MillisDuration, mut step_fn: F) -> impl Iterator<Item = MillisDuration>
+    where
+        F: FnMut(MillisDuration) -> Option<MillisDuration>,
+    {
+        core::iter::successors(Some(init), move |previous| step_fn(*previous))
+    }
+
+    /// Splits the duration into whole seconds and the remaining milliseconds (0-999).
+    ///
+    /// Useful for formatting durations such as `"1.500 s"` without going through floats.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// let (secs, millis) = MillisDuration::from_millis(1500).secs_and_millis();
+    /// assert_eq!(format!("{secs}.{millis:03} s"), "1.500 s");
+    /// ```
+    #[inline]
+    pub fn secs_and_millis(&self) -> (u64, u16) {
+        (self.0 / 1000, (self.0 % 1000) as u16)
+    }
+
+    /// Converts a `Duration`, truncating sub-millisecond precision.
+    ///
+    /// This is the fallible counterpart of `From<Duration>`, which saturates instead. It is a
+    /// method rather than a `TryFrom` impl, since the standard library already derives
+    /// `TryFrom<Duration>` from the `From` impl.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the duration is longer than `u64::MAX` milliseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// use std::time::Duration;
+    /// assert_eq!(
+    ///     MillisDuration::try_from_duration(Duration::from_secs(2)),
+    ///     Ok(MillisDuration::from_millis(2000))
+    /// );
+    /// assert!(MillisDuration::try_from_duration(Duration::MAX).is_err());
+    /// ```
+    pub fn try_from_duration(duration: Duration) -> Result<Self, &'static str> {
+        u64::try_from(duration.as_millis())
+            .map(Self)
+            .map_err(|_| "duration is too large")
+    }
+
+    /// Converts a `Duration`, also reporting whether sub-millisecond precision was lost.
+    ///
+    /// Durations longer than `u64::MAX` milliseconds saturate like `From<Duration>`.
+    ///
+    /// # Returns
+    ///
+    /// The truncated duration, and `true` if `duration` was not a whole number of milliseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// use std::time::Duration;
+    /// let (duration, lossy) = MillisDuration::from_duration_lossy(Duration::from_micros(1500));
+    /// assert_eq!(duration, MillisDuration::from_millis(1));
+    /// assert!(lossy);
+    /// ```
+    pub fn from_duration_lossy(duration: Duration) -> (Self, bool) {
+        (
+            Self::from(duration),
+            !duration.subsec_nanos().is_multiple_of(1_000_000),
+        )
+    }
+
+    /// Formats the duration as a zero-padded `"HH:MM:SS.mmm"` timecode.
+    ///
+    /// Durations of 100 hours or more get as many hour digits as needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// assert_eq!(MillisDuration::from_millis(3_661_500).format_hms(), "01:01:01.500");
+    /// ```
+    pub fn format_hms(&self) -> String {
+        let (secs, millis) = self.secs_and_millis();
+        format!(
+            "{:02}:{:02}:{:02}.{millis:03}",
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60
+        )
+    }
+
+    /// Parses a `"HH:MM:SS.mmm"` timecode, as produced by [`MillisDuration::format_hms`].
+    ///
+    /// The hours and the `.mmm` fraction are optional, so `"MM:SS"` is accepted too. Minutes and
+    /// seconds must be two digits below 60, and the fraction must be exactly three digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not a valid timecode, or if it overflows `u64` milliseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// assert_eq!(MillisDuration::parse_hms("01:01:01.500"), Ok(MillisDuration::from_millis(3_661_500)));
+    /// assert_eq!(MillisDuration::parse_hms("02:30"), Ok(MillisDuration::from_millis(150_000)));
+    /// assert!(MillisDuration::parse_hms("1:2:3").is_err());
+    /// ```
+    pub fn parse_hms(s: &str) -> Result<Self, &'static str> {
+        fn number(field: &str) -> Result<u64, &'static str> {
+            if field.is_empty() || !field.bytes().all(|byte| byte.is_ascii_digit()) {
+                return Err("expected a timecode like HH:MM:SS.mmm");
+            }
+            field
+                .parse()
+                .map_err(|_| "duration overflows u64 milliseconds")
+        }
+
+        fn sixty(field: &str) -> Result<u64, &'static str> {
+            let value = number(field)?;
+            if field.len() != 2 || value >= 60 {
+                return Err("minutes and seconds must be two digits below 60");
+            }
+            Ok(value)
+        }
+
+        let (clock, millis) = match s.split_once('.') {
+            Some((clock, fraction)) if fraction.len() == 3 => (clock, number(fraction)?),
+            Some(_) => return Err("milliseconds must be three digits"),
+            None => (s, 0),
+        };
+        let fields: Vec<&str> = clock.split(':').collect();
+        let (hours, minutes, seconds) = match fields.as_slice() {
+            [minutes, seconds] => (0, sixty(minutes)?, sixty(seconds)?),
+            [hours, minutes, seconds] if hours.len() >= 2 => {
+                (number(hours)?, sixty(minutes)?, sixty(seconds)?)
+            }
+            _ => return Err("expected a timecode like HH:MM:SS.mmm"),
+        };
+        hours
+            .checked_mul(3600)
+            .and_then(|secs| secs.checked_add(minutes * 60 + seconds))
+            .and_then(|secs| secs.checked_mul(1000))
+            .and_then(|total| total.checked_add(millis))
+            .map(Self)
+            .ok_or("duration overflows u64 milliseconds")
+    }
+
+    /// Returns a human-readable view of the duration, such as `"1h 1m 1s"`.
     ///
-    /// # Arguments
+    /// Zero components are left out, and a zero duration is shown as `"0ms"`. The regular
+    /// `Display` implementation is unaffected.
     ///
-    /// * `earlier` - The earlier monotonic timestamp.
+    /// # Examples
     ///
-    /// # Returns
+    /// ```
+    /// use monotonic_time_rs::MillisDuration;
+    /// assert_eq!(MillisDuration::from_millis(3_661_000).human().to_string(), "1h 1m 1s");
+    /// assert_eq!(MillisDuration::from_millis(1500).human().to_string(), "1s 500ms");
+    /// ```
+    pub fn human(&self) -> impl fmt::Display {
+        HumanMillisDuration(*self)
+    }
+
+    /// Returns how many whole `rhs` fit in the duration, rounding down.
     ///
-    /// A `MillisDuration` representing the elapsed time.
+    /// This is the integer quotient of two durations. Use [`MillisDuration::ratio_of`] for the
+    /// fractional ratio.
     ///
     /// # Panics
     ///
-    /// Panics if `self` is earlier than `earlier`.
+    /// Panics if `rhs` is zero.
     ///
     /// # Examples
     ///
     /// ```
-    /// use monotonic_time_rs::Millis;
-    /// let start = Millis::new(1000);
-    /// let end = Millis::new(5000);
-    /// let duration = end.duration_since_ms(start);
-    /// assert_eq!(duration.as_millis(), 4000);
+    /// use monotonic_time_rs::MillisDuration;
+    /// let frame = MillisDuration::from_millis(16);
+    /// assert_eq!(MillisDuration::from_millis(50).div_duration_floor(frame), 3);
     /// ```
-    pub fn duration_since_ms(&self, earlier: Millis) -> MillisDuration {
-        self.checked_duration_since_ms(earlier)
-            .expect("Millis::duration_since_ms called with a later timestamp")
-    }
-}
-
-impl AddAssign<MillisDuration> for Millis {
-    fn add_assign(&mut self, other: MillisDuration) {
-        self.0 += other.0;
-    }
-}
-
-impl SubAssign<MillisDuration> for Millis {
-    fn sub_assign(&mut self, other: MillisDuration) {
-        self.0 -= other.0;
-    }
-}
-
-impl Add<MillisDuration> for Millis {
-    type Output = Self;
-
-    fn add(self, other: MillisDuration) -> Self::Output {
-        Self(self.0 + other.0)
-    }
-}
-
-impl Sub<MillisDuration> for Millis {
-    type Output = Self;
-
-    fn sub(self, other: MillisDuration) -> Self::Output {
-        Self(self.0 - other.0)
+    pub fn div_duration_floor(self, rhs: MillisDuration) -> u64 {
+        self.0
+            .checked_div(rhs.0)
+            .expect("divide by zero error millisduration")
     }
-}
-
-/// Represents the lower 16 bits of a timestamp in milliseconds.
-///
-/// This type alias is used for efficient serialization scenarios where only a subset of the
-/// timestamp is needed.
-pub type MillisLow16 = u16;
-
-/// Represents a duration in milliseconds.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct MillisDuration(u64);
 
-impl MillisDuration {
-    /// Creates a new `MillisDuration` instance from milliseconds.
+    /// Returns how many `rhs` are needed to cover the duration, rounding up.
     ///
-    /// # Arguments
+    /// # Panics
     ///
-    /// * `millis` - The duration in milliseconds.
+    /// Panics if `rhs` is zero.
     ///
     /// # Examples
     ///
     /// ```
     /// use monotonic_time_rs::MillisDuration;
-    /// let duration = MillisDuration::from_millis(4000);
+    /// let frame = MillisDuration::from_millis(16);
+    /// assert_eq!(MillisDuration::from_millis(50).div_duration_ceil(frame), 4);
     /// ```
-    #[inline]
-    pub const fn from_millis(millis: u64) -> Self {
-        Self(millis)
+    pub fn div_duration_ceil(self, rhs: MillisDuration) -> u64 {
+        assert!(rhs.0 != 0, "divide by zero error millisduration");
+        self.0.div_ceil(rhs.0)
     }
 
-    /// Creates a new `MillisDuration` from a number of seconds.
-    /// Returns an error if the input is negative.
+    /// Buckets this duration into a [`LatencyClass`] using two thresholds.
+    ///
+    /// Both thresholds are inclusive upper bounds, and the fast cut-off is half of
+    /// `normal_max`, truncated to whole milliseconds:
+    ///
+    /// * [`LatencyClass::Fast`] - at most `normal_max / 2`.
+    /// * [`LatencyClass::Normal`] - up to and including `normal_max`.
+    /// * [`LatencyClass::Slow`] - up to and including `slow_max`.
+    /// * [`LatencyClass::Critical`] - above `slow_max`.
+    ///
+    /// Use [`MillisDuration::classify_with`] to choose the fast cut-off.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `normal_max` is greater than `slow_max`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use monotonic_time_rs::MillisDuration;
-    /// let duration = MillisDuration::from_secs(2.5).unwrap();
-    /// assert_eq!(duration.as_millis(), 2500);
+    /// use monotonic_time_rs::{LatencyClass, MillisDuration};
+    /// let normal_max = MillisDuration::from_millis(100);
+    /// let slow_max = MillisDuration::from_millis(500);
+    /// assert_eq!(MillisDuration::from_millis(30).classify(normal_max, slow_max), LatencyClass::Fast);
+    /// assert_eq!(MillisDuration::from_millis(100).classify(normal_max, slow_max), LatencyClass::Normal);
+    /// assert_eq!(MillisDuration::from_millis(501).classify(normal_max, slow_max), LatencyClass::Critical);
     /// ```
-    #[inline]
-    pub fn from_secs(seconds: f32) -> Result<Self, &'static str> {
-        if seconds < 0.0 {
-            return Err("must be a positive value");
-        }
-        Ok(Self((seconds * 1000.0) as u64))
+    pub fn classify(&self, normal_max: MillisDuration, slow_max: MillisDuration) -> LatencyClass {
+        self.classify_with(normal_max / 2, normal_max, slow_max)
     }
 
-    /// Returns the duration in milliseconds.
+    /// Buckets this duration into a [`LatencyClass`] using an explicit fast threshold.
+    ///
+    /// Like [`MillisDuration::classify`], but [`LatencyClass::Fast`] covers durations up to
+    /// and including `fast_max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `fast_max <= normal_max <= slow_max`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use monotonic_time_rs::MillisDuration;
-    /// let duration = MillisDuration::from_millis(4000);
-    /// assert_eq!(duration.as_millis(), 4000);
+    /// use monotonic_time_rs::{LatencyClass, MillisDuration};
+    /// let fast_max = MillisDuration::from_millis(20);
+    /// let normal_max = MillisDuration::from_millis(100);
+    /// let slow_max = MillisDuration::from_millis(500);
+    /// let latency = MillisDuration::from_millis(30);
+    /// assert_eq!(latency.classify_with(fast_max, normal_max, slow_max), LatencyClass::Normal);
     /// ```
-    #[inline]
-    pub fn as_millis(&self) -> u64 {
-        self.0
+    pub fn classify_with(
+        &self,
+        fast_max: MillisDuration,
+        normal_max: MillisDuration,
+        slow_max: MillisDuration,
+    ) -> LatencyClass {
+        assert!(
+            fast_max <= normal_max,
+            "classify fast_max must not be greater than normal_max"
+        );
+        assert!(
+            normal_max <= slow_max,
+            "classify normal_max must not be greater than slow_max"
+        );
+        if *self <= fast_max {
+            LatencyClass::Fast
+        } else if *self <= normal_max {
+            LatencyClass::Normal
+        } else if *self <= slow_max {
+            LatencyClass::Slow
+        } else {
+            LatencyClass::Critical
+        }
     }
+}
 
-    pub fn as_secs(&self) -> f32 {
-        self.0 as f32 / 1000.0
+/// Parses an integer with an optional unit suffix: `ms`, `s`, `m` or `h`.
+///
+/// A bare integer is interpreted as milliseconds, matching `MillisDuration::from_millis` and the
+/// plain serde form. Surrounding whitespace, and whitespace before the unit, is ignored.
+///
+/// # Errors
+///
+/// Returns a descriptive error if there is no leading integer, the unit is unknown, or the
+/// result overflows `u64` milliseconds.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::MillisDuration;
+/// assert_eq!("1500ms".parse(), Ok(MillisDuration::from_millis(1500)));
+/// assert_eq!("2s".parse(), Ok(MillisDuration::from_millis(2000)));
+/// assert_eq!("250".parse(), Ok(MillisDuration::from_millis(250)));
+/// ```
+impl FromStr for MillisDuration {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let digits_end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (digits, unit) = s.split_at(digits_end);
+        if digits.is_empty() {
+            return Err("expected an integer duration");
+        }
+        let value: u64 = digits
+            .parse()
+            .map_err(|_| "duration overflows u64 milliseconds")?;
+        let factor = match unit.trim_start() {
+            "" | "ms" => 1,
+            "s" => 1000,
+            "m" => 60 * 1000,
+            "h" => 60 * 60 * 1000,
+            _ => return Err("unknown duration unit, expected ms, s, m or h"),
+        };
+        value
+            .checked_mul(factor)
+            .map(Self::from_millis)
+            .ok_or("duration overflows u64 milliseconds")
+    }
+}
+
+struct HumanMillisDuration(MillisDuration);
+
+impl fmt::Display for HumanMillisDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (secs, millis) = self.0.secs_and_millis();
+        if secs == 0 && millis == 0 {
+            return write!(f, "0ms");
+        }
+        let components = [
+            (secs / 3600, "h"),
+            (secs / 60 % 60, "m"),
+            (secs % 60, "s"),
+            (u64::from(millis), "ms"),
+        ];
+        let mut separator = "";
+        for (value, unit) in components {
+            if value != 0 {
+                write!(f, "{separator}{value}{unit}")?;
+                separator = " ";
+            }
+        }
+        Ok(())
     }
 }
 
+/// Formats the duration as `"N ms"`, or in seconds when a precision is given.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::MillisDuration;
+/// let duration = MillisDuration::from_millis(1500);
+/// assert_eq!(format!("{duration}"), "1500 ms");
+/// assert_eq!(format!("{duration:.2}"), "1.50 s");
+/// ```
 impl fmt::Display for MillisDuration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} ms", self.0)
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*} s", precision, self.as_secs_f64()),
+            None => write!(f, "{} ms", self.0),
+        }
     }
 }
 
@@ -334,6 +1989,70 @@ impl From<MillisDuration> for u64 {
     }
 }
 
+/// Converts a `Duration`, truncating sub-millisecond precision.
+///
+/// Durations longer than `u64::MAX` milliseconds saturate at `u64::MAX`. Use
+/// [`MillisDuration::try_from_duration`] to detect that case instead.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::MillisDuration;
+/// use std::time::Duration;
+/// let duration = MillisDuration::from(Duration::from_micros(1500));
+/// assert_eq!(duration, MillisDuration::from_millis(1));
+/// ```
+impl From<Duration> for MillisDuration {
+    #[inline]
+    fn from(duration: Duration) -> Self {
+        Self(u64::try_from(duration.as_millis()).unwrap_or(u64::MAX))
+    }
+}
+
+/// Compares against a `Duration`, truncated to whole milliseconds.
+///
+/// A `Duration` of 1.5 ms is therefore equal to a `MillisDuration` of 1 ms.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::MillisDuration;
+/// use std::time::Duration;
+/// assert_eq!(MillisDuration::from_millis(1500), Duration::from_millis(1500));
+/// assert!(MillisDuration::from_millis(1500) < Duration::from_secs(2));
+/// ```
+impl PartialEq<Duration> for MillisDuration {
+    #[inline]
+    fn eq(&self, other: &Duration) -> bool {
+        u128::from(self.0) == other.as_millis()
+    }
+}
+
+/// Orders against a `Duration`, truncated to whole milliseconds.
+impl PartialOrd<Duration> for MillisDuration {
+    #[inline]
+    fn partial_cmp(&self, other: &Duration) -> Option<core::cmp::Ordering> {
+        u128::from(self.0).partial_cmp(&other.as_millis())
+    }
+}
+
+/// Converts into a `Duration`, for APIs such as `std::thread::sleep`. This is lossless.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::MillisDuration;
+/// use std::time::Duration;
+/// let duration: Duration = MillisDuration::from_millis(1500).into();
+/// assert_eq!(duration, Duration::from_millis(1500));
+/// ```
+impl From<MillisDuration> for Duration {
+    #[inline]
+    fn from(duration: MillisDuration) -> Self {
+        Duration::from_millis(duration.0)
+    }
+}
+
 impl Mul<f32> for MillisDuration {
     type Output = MillisDuration;
 
@@ -354,7 +2073,11 @@ impl Mul<u32> for MillisDuration {
     type Output = MillisDuration;
     #[inline]
     fn mul(self, rhs: u32) -> Self::Output {
-        Self::from_millis(((self.0 as u32) * rhs) as u64)
+        Self::from_millis(
+            self.0
+                .checked_mul(rhs as u64)
+                .expect("overflow on mul millisduration"),
+        )
     }
 }
 
@@ -363,7 +2086,7 @@ impl Mul<MillisDuration> for u32 {
 
     #[inline]
     fn mul(self, rhs: MillisDuration) -> Self::Output {
-        MillisDuration::from_millis((self * (rhs.0 as u32)) as u64)
+        rhs * self
     }
 }
 
@@ -380,6 +2103,29 @@ impl Add for MillisDuration {
     }
 }
 
+/// Sums durations, panicking on overflow like `+`.
+///
+/// Use [`MillisDuration::checked_sum`] to detect overflow instead.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::MillisDuration;
+/// let frame_times = [16, 17, 16].map(MillisDuration::from_millis);
+/// assert_eq!(frame_times.iter().sum::<MillisDuration>(), MillisDuration::from_millis(49));
+/// ```
+impl core::iter::Sum for MillisDuration {
+    fn sum<I: Iterator<Item = MillisDuration>>(iter: I) -> Self {
+        iter.fold(MillisDuration::default(), Add::add)
+    }
+}
+
+impl<'a> core::iter::Sum<&'a MillisDuration> for MillisDuration {
+    fn sum<I: Iterator<Item = &'a MillisDuration>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 impl AddAssign for MillisDuration {
     #[inline]
     fn add_assign(&mut self, rhs: MillisDuration) {
@@ -434,6 +2180,34 @@ impl DivAssign<u32> for MillisDuration {
     }
 }
 
+/// Divides the duration by a float, rounding to the nearest millisecond.
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero, negative or NaN, or if the result exceeds `u64::MAX` milliseconds.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::MillisDuration;
+/// let duration = MillisDuration::from_millis(1000) / 4.0;
+/// assert_eq!(duration, MillisDuration::from_millis(250));
+/// ```
+impl Div<f32> for MillisDuration {
+    type Output = MillisDuration;
+
+    #[inline]
+    fn div(self, rhs: f32) -> MillisDuration {
+        assert!(
+            rhs > 0.0,
+            "divide by zero, negative or NaN error millisduration"
+        );
+        let quotient = float::round(self.0 as f64 / rhs as f64);
+        assert!(quotient < u64::MAX as f64, "overflow on div millisduration");
+        Self::from_millis(quotient as u64)
+    }
+}
+
 /// Implements subtraction between two `Millis` instances, returning a `MillisDuration`.
 ///
 /// # Panics
@@ -464,6 +2238,45 @@ impl Sub for Millis {
     }
 }
 
+/// Implements a binary operator for the reference combinations of an owned `impl`, the same way
+/// the standard library does for primitive types.
+macro_rules! forward_ref_binop {
+    (impl $imp:ident, $method:ident for $t:ty, $u:ty) => {
+        impl $imp<$u> for &$t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            #[inline]
+            fn $method(self, other: $u) -> Self::Output {
+                $imp::$method(*self, other)
+            }
+        }
+
+        impl $imp<&$u> for $t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            #[inline]
+            fn $method(self, other: &$u) -> Self::Output {
+                $imp::$method(self, *other)
+            }
+        }
+
+        impl $imp<&$u> for &$t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            #[inline]
+            fn $method(self, other: &$u) -> Self::Output {
+                $imp::$method(*self, *other)
+            }
+        }
+    };
+}
+
+forward_ref_binop! { impl Add, add for Millis, MillisDuration }
+forward_ref_binop! { impl Sub, sub for Millis, MillisDuration }
+forward_ref_binop! { impl Sub, sub for Millis, Millis }
+forward_ref_binop! { impl Add, add for MillisDuration, MillisDuration }
+forward_ref_binop! { impl Sub, sub for MillisDuration, MillisDuration }
+
 impl From<u64> for Millis {
     #[inline]
     fn from(ms: u64) -> Self {
@@ -518,16 +2331,37 @@ pub trait MonotonicClock {
     /// }
     /// ```
     fn now(&self) -> Millis;
+
+    /// Returns the current monotonic time, or `None` if the clock could not be read.
+    ///
+    /// The default implementation never fails and returns `Some(self.now())`. Clocks backed by a
+    /// fallible time source should override it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{InstantMonotonicClock, MonotonicClock};
+    /// let clock = InstantMonotonicClock::new();
+    /// assert!(clock.now_checked().is_some());
+    /// ```
+    fn now_checked(&self) -> Option<Millis> {
+        Some(self.now())
+    }
 }
 
 /// A concrete implementation of `MonotonicClock` using `std::time::Instant`.
 ///
 /// This struct captures the instant when it was created and provides
 /// the elapsed time since then as a `Millis` timestamp.
+///
+/// Only available with the `std` feature.
+#[cfg(feature = "std")]
 pub struct InstantMonotonicClock {
     started: Instant,
+    base: Millis,
 }
 
+#[cfg(feature = "std")]
 impl InstantMonotonicClock {
     /// Creates a new `InstantMonotonicClock` instance, capturing the current instant.
     ///
@@ -538,18 +2372,38 @@ impl InstantMonotonicClock {
     /// let clock = InstantMonotonicClock::new();
     /// ```
     pub fn new() -> Self {
+        Self::with_base(Millis::new(0))
+    }
+
+    /// Creates a new `InstantMonotonicClock` whose `now()` starts at `base` instead of zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The timestamp reported at the moment of creation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{InstantMonotonicClock, Millis, MonotonicClock};
+    /// let clock = InstantMonotonicClock::with_base(Millis::new(1_000_000));
+    /// assert!(clock.now() >= Millis::new(1_000_000));
+    /// ```
+    pub fn with_base(base: Millis) -> Self {
         Self {
             started: Instant::now(),
+            base,
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl Default for InstantMonotonicClock {
     fn default() -> Self {
         Self::new()
     }
 }
 
+#[cfg(feature = "std")]
 impl MonotonicClock for InstantMonotonicClock {
     /// Returns the elapsed monotonic time since the creation of the `InstantMonotonicClock`.
     ///
@@ -562,12 +2416,40 @@ impl MonotonicClock for InstantMonotonicClock {
     /// let current_time = clock.now();
     /// assert!(current_time.absolute_milliseconds() >= 500);
     /// ```
+    #[inline]
     fn now(&self) -> Millis {
         let duration = Instant::now().duration_since(self.started);
-        Millis::new(duration.as_millis() as u64)
+        Millis::new(self.base.0 + duration.as_millis() as u64)
+    }
+}
+
+#[cfg(feature = "std")]
+impl InstantMonotonicClock {
+    /// Returns the elapsed time like `now()`, but with microsecond resolution.
+    ///
+    /// The base passed to [`InstantMonotonicClock::with_base`] is converted to microseconds, so
+    /// `now_micros()` and `now()` share the same epoch.
+    ///
+    /// The result saturates at `u64::MAX` microseconds, which a base above
+    /// `u64::MAX / 1000` milliseconds already reaches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{InstantMonotonicClock, Millis, MonotonicClock};
+    /// let clock = InstantMonotonicClock::with_base(Millis::new(1000));
+    /// let micros = clock.now_micros();
+    /// assert!(Millis::from(micros) <= clock.now());
+    /// ```
+    pub fn now_micros(&self) -> Micros {
+        let duration = Instant::now().duration_since(self.started);
+        let elapsed = u64::try_from(duration.as_micros()).unwrap_or(u64::MAX);
+        Micros::new(self.base.0.saturating_mul(1000).saturating_add(elapsed))
     }
 }
 
+#[cfg(feature = "std")]
+#[inline]
 pub fn create_monotonic_clock() -> impl MonotonicClock {
     #[cfg(target_arch = "wasm32")]
     use crate::wasm::WasmMonotonicClock;
@@ -580,3 +2462,43 @@ pub fn create_monotonic_clock() -> impl MonotonicClock {
         InstantMonotonicClock::new()
     }
 }
+
+/// Creates the platform default clock and passes it to `f`.
+///
+/// Handy for one-off timing in functions that take a `&dyn MonotonicClock`, without having to
+/// construct and thread a clock through.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{with_default_clock, MonotonicClock};
+/// let now = with_default_clock(|clock| clock.now());
+/// println!("{now}");
+/// ```
+#[cfg(feature = "std")]
+pub fn with_default_clock<R>(f: impl FnOnce(&dyn MonotonicClock) -> R) -> R {
+    let clock = create_monotonic_clock();
+    f(&clock)
+}
+
+/// Returns the signed difference `a.now() - b.now()` between two clocks.
+///
+/// Unlike subtracting two `Millis`, this never panics because `b` is ahead, which makes it
+/// suitable for measuring the offset between clocks with unrelated epochs.
+///
+/// # Panics
+///
+/// Panics if the difference does not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{clock_delta, InstantMonotonicClock, Millis};
+/// let a = InstantMonotonicClock::new();
+/// let b = InstantMonotonicClock::with_base(Millis::new(60_000));
+/// assert!(clock_delta(&a, &b).is_negative());
+/// ```
+pub fn clock_delta(a: &impl MonotonicClock, b: &impl MonotonicClock) -> SignedMillisDuration {
+    let difference = a.now().0 as i128 - b.now().0 as i128;
+    SignedMillisDuration::from_millis(i64::try_from(difference).expect("overflow on clock_delta"))
+}
@@ -0,0 +1,76 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MillisLow16};
+
+/// Reconstructs full timestamps from a stream of 16-bit lower bits by counting wrap-arounds.
+///
+/// The lower 16 bits wrap every 65 536 ms. The tracker keeps a generation, the upper 48 bits,
+/// and increments it whenever the lower bits jump back by more than half the range, which can
+/// only be a wrap. That keeps reconstruction working for as long as updates arrive at least
+/// every ~32 seconds, without needing a local clock.
+///
+/// Lower bits that are slightly behind the latest ones, such as reordered packets, are resolved
+/// relative to the latest value, including ones from just before a wrap.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{LowTracker, Millis};
+/// let mut tracker = LowTracker::new(Millis::new(0x1_fff0));
+/// assert_eq!(tracker.update(0xfffa), Some(Millis::new(0x1_fffa)));
+/// assert_eq!(tracker.update(0x0004), Some(Millis::new(0x2_0004)));
+/// assert_eq!(tracker.generation(), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LowTracker {
+    latest: Millis,
+}
+
+impl LowTracker {
+    /// Creates a tracker anchored at `start`, a recent full timestamp of the stream.
+    pub fn new(start: Millis) -> Self {
+        Self { latest: start }
+    }
+
+    /// Reconstructs the full timestamp for `lower` and advances the generation on a wrap.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Millis)` - The reconstructed timestamp.
+    /// * `None` - If the reconstructed timestamp would be before the epoch or overflow `u64`,
+    ///   for example lower bits from just before a wrap while the latest timestamp is still
+    ///   near zero. The tracker is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{LowTracker, Millis};
+    /// let mut tracker = LowTracker::new(Millis::new(100));
+    /// assert_eq!(tracker.update(0xfff0), None);
+    /// assert_eq!(tracker.latest(), Millis::new(100));
+    /// ```
+    pub fn update(&mut self, lower: MillisLow16) -> Option<Millis> {
+        let offset = lower.wrapping_sub(self.latest.to_lower()) as i16;
+        let reconstructed = Millis::new(
+            self.latest
+                .absolute_milliseconds()
+                .checked_add_signed(i64::from(offset))?,
+        );
+        self.latest = self.latest.max(reconstructed);
+        Some(reconstructed)
+    }
+
+    /// Returns the number of completed 16-bit wraps, the upper 48 bits of the latest timestamp.
+    #[inline]
+    pub fn generation(&self) -> u64 {
+        self.latest.absolute_milliseconds() >> 16
+    }
+
+    /// Returns the latest reconstructed timestamp.
+    #[inline]
+    pub fn latest(&self) -> Millis {
+        self.latest
+    }
+}
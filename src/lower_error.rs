@@ -0,0 +1,40 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::MillisDuration;
+use core::fmt;
+
+/// The reason a timestamp could not be reconstructed from its lower bits.
+///
+/// Returned by [`Millis::try_from_lower`](crate::Millis::try_from_lower).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FromLowerError {
+    /// The reconstructed timestamp is further before the current time than the threshold.
+    SkewExceeded {
+        /// How far the reconstructed timestamp is before the current time.
+        diff: MillisDuration,
+        /// The largest accepted difference.
+        threshold: MillisDuration,
+    },
+    /// The lower bits wrapped around, but the current time is too close to the epoch for the
+    /// timestamp to be from before the wrap.
+    BeforeEpoch,
+}
+
+impl fmt::Display for FromLowerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SkewExceeded { diff, threshold } => write!(
+                f,
+                "reconstructed timestamp is {} ms old, more than the {} ms threshold",
+                diff.as_millis(),
+                threshold.as_millis()
+            ),
+            Self::BeforeEpoch => write!(f, "reconstructed timestamp is before the epoch"),
+        }
+    }
+}
+
+impl core::error::Error for FromLowerError {}
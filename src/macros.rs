@@ -0,0 +1,33 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+/// Asserts that two `Millis` or `MillisDuration` values are within a tolerance of each other.
+///
+/// All three arguments may be anything that converts into a `u64` millisecond count, such as
+/// `Millis`, `MillisDuration` or a plain `u64`. On failure, the message shows both values and
+/// how far apart they are.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{assert_millis_approx, Millis, MillisDuration};
+/// assert_millis_approx!(Millis::new(1040), Millis::new(1000), MillisDuration::from_millis(50));
+/// assert_millis_approx!(MillisDuration::from_millis(980), MillisDuration::from_millis(1000), 20u64);
+/// ```
+#[macro_export]
+macro_rules! assert_millis_approx {
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {{
+        let left: u64 = ($left).into();
+        let right: u64 = ($right).into();
+        let tolerance: u64 = ($tolerance).into();
+        let difference = left.abs_diff(right);
+        if difference > tolerance {
+            panic!(
+                "assertion `left ≈ right` failed: difference {} ms exceeds tolerance {} ms\n  left: {} ms\n right: {} ms",
+                difference, tolerance, left, right
+            );
+        }
+    }};
+}
@@ -0,0 +1,59 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MillisDuration, MonotonicClock};
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// A `MonotonicClock` that only advances when told to, for deterministic tests.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{ManualClock, Millis, MillisDuration, MonotonicClock};
+/// let clock = ManualClock::new(Millis::new(1000));
+/// clock.advance(MillisDuration::from_millis(500));
+/// assert_eq!(clock.now(), Millis::new(1500));
+/// clock.set(Millis::new(4000));
+/// assert_eq!(clock.now(), Millis::new(4000));
+/// ```
+#[derive(Debug)]
+pub struct ManualClock {
+    now: AtomicU64,
+}
+
+impl ManualClock {
+    /// Creates a clock that reports `start` until it is advanced or set.
+    pub fn new(start: Millis) -> Self {
+        Self {
+            now: AtomicU64::new(start.absolute_milliseconds()),
+        }
+    }
+
+    /// Moves the clock forward by `duration`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting time overflows `u64`.
+    pub fn advance(&self, duration: MillisDuration) {
+        self.now
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |now| {
+                now.checked_add(duration.as_millis())
+            })
+            .expect("overflow on ManualClock advance");
+    }
+
+    /// Sets the clock to `now`.
+    ///
+    /// This does not enforce monotonicity, so tests can also simulate a misbehaving clock.
+    pub fn set(&self, now: Millis) {
+        self.now
+            .store(now.absolute_milliseconds(), Ordering::SeqCst);
+    }
+}
+
+impl MonotonicClock for ManualClock {
+    fn now(&self) -> Millis {
+        Millis::new(self.now.load(Ordering::SeqCst))
+    }
+}
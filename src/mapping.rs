@@ -0,0 +1,71 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{float, Millis};
+
+/// Converts timestamps from one clock's timeline to another's by linear interpolation.
+///
+/// The mapping is defined by two anchor pairs: `a1` on clock A corresponds to `b1` on clock B,
+/// and `a2` corresponds to `b2`. Timestamps outside the anchors are extrapolated.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{ClockMapping, Millis};
+/// let mapping = ClockMapping::from_anchors(
+///     Millis::new(1000),
+///     Millis::new(5000),
+///     Millis::new(2000),
+///     Millis::new(7000),
+/// )
+/// .unwrap();
+/// assert_eq!(mapping.map(Millis::new(1500)), Millis::new(6000));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClockMapping {
+    a_origin: f64,
+    b_origin: f64,
+    scale: f64,
+}
+
+impl ClockMapping {
+    /// Creates a mapping from two pairs of corresponding timestamps.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `a1` equals `a2`, since the mapping would be undefined.
+    pub fn from_anchors(
+        a1: Millis,
+        b1: Millis,
+        a2: Millis,
+        b2: Millis,
+    ) -> Result<Self, &'static str> {
+        if a1 == a2 {
+            return Err("anchor timestamps on the source clock must differ");
+        }
+        let a1 = a1.absolute_milliseconds() as f64;
+        let b1 = b1.absolute_milliseconds() as f64;
+        let a2 = a2.absolute_milliseconds() as f64;
+        let b2 = b2.absolute_milliseconds() as f64;
+        Ok(Self {
+            a_origin: a1,
+            b_origin: b1,
+            scale: (b2 - b1) / (a2 - a1),
+        })
+    }
+
+    /// Returns how fast clock B advances relative to clock A.
+    #[inline]
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Maps a timestamp on clock A to clock B, rounded to the nearest millisecond.
+    ///
+    /// Results before the epoch are clamped to zero.
+    pub fn map(&self, a: Millis) -> Millis {
+        let b = self.b_origin + (a.absolute_milliseconds() as f64 - self.a_origin) * self.scale;
+        Millis::new(float::round(b) as u64)
+    }
+}
@@ -0,0 +1,154 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MillisDuration};
+use core::time::Duration;
+
+/// Represents a monotonic absolute timestamp with microsecond resolution.
+///
+/// The microsecond counterpart of [`Millis`], for profiling where milliseconds are too coarse.
+/// Converting from `Millis` multiplies by 1000, and converting to `Millis` truncates the
+/// sub-millisecond part.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{Micros, Millis};
+/// let timestamp = Micros::new(1_500_999);
+/// assert_eq!(Millis::from(timestamp), Millis::new(1500));
+/// assert_eq!(Micros::from(Millis::new(1500)), Micros::new(1_500_000));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Micros(u64);
+
+impl Micros {
+    /// Creates a new `Micros` from an absolute time in microseconds.
+    #[inline]
+    pub const fn new(absolute_time: u64) -> Self {
+        Self(absolute_time)
+    }
+
+    /// Returns the underlying microseconds value.
+    #[inline]
+    pub const fn absolute_microseconds(&self) -> u64 {
+        self.0
+    }
+
+    /// Calculates the duration since another `Micros`, returning `None` if `self` is earlier.
+    pub fn checked_duration_since(&self, earlier: Micros) -> Option<MicrosDuration> {
+        self.0.checked_sub(earlier.0).map(MicrosDuration)
+    }
+
+    /// Calculates the duration since another `Micros`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is earlier than `earlier`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{Micros, MicrosDuration};
+    /// let start = Micros::new(1000);
+    /// let end = Micros::new(1250);
+    /// assert_eq!(end.duration_since(start), MicrosDuration::from_micros(250));
+    /// ```
+    pub fn duration_since(&self, earlier: Micros) -> MicrosDuration {
+        self.checked_duration_since(earlier)
+            .expect("Micros::duration_since called with a later timestamp")
+    }
+
+    /// Converts to `Millis`, truncating the sub-millisecond part.
+    #[inline]
+    pub fn to_millis(&self) -> Millis {
+        Millis::new(self.0 / 1000)
+    }
+}
+
+/// Converts exactly, by multiplying by 1000.
+///
+/// # Panics
+///
+/// Panics if the timestamp does not fit in `u64` microseconds, about 584 000 years.
+impl From<Millis> for Micros {
+    fn from(millis: Millis) -> Self {
+        Self(
+            millis
+                .absolute_milliseconds()
+                .checked_mul(1000)
+                .expect("overflow on millis to micros"),
+        )
+    }
+}
+
+/// Converts by truncating the sub-millisecond part.
+impl From<Micros> for Millis {
+    #[inline]
+    fn from(micros: Micros) -> Self {
+        micros.to_millis()
+    }
+}
+
+/// Represents a duration in microseconds.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{MicrosDuration, MillisDuration};
+/// let duration = MicrosDuration::from_micros(2_750);
+/// assert_eq!(MillisDuration::from(duration), MillisDuration::from_millis(2));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MicrosDuration(u64);
+
+impl MicrosDuration {
+    /// Creates a new `MicrosDuration` from microseconds.
+    #[inline]
+    pub const fn from_micros(micros: u64) -> Self {
+        Self(micros)
+    }
+
+    /// Returns the duration in microseconds.
+    #[inline]
+    pub const fn as_micros(&self) -> u64 {
+        self.0
+    }
+
+    /// Converts to `MillisDuration`, truncating the sub-millisecond part.
+    #[inline]
+    pub const fn to_millis_duration(&self) -> MillisDuration {
+        MillisDuration::from_millis(self.0 / 1000)
+    }
+}
+
+/// Converts exactly, by multiplying by 1000.
+///
+/// # Panics
+///
+/// Panics if the duration does not fit in `u64` microseconds.
+impl From<MillisDuration> for MicrosDuration {
+    fn from(duration: MillisDuration) -> Self {
+        Self(
+            duration
+                .as_millis()
+                .checked_mul(1000)
+                .expect("overflow on millis to micros"),
+        )
+    }
+}
+
+/// Converts by truncating the sub-millisecond part.
+impl From<MicrosDuration> for MillisDuration {
+    #[inline]
+    fn from(duration: MicrosDuration) -> Self {
+        duration.to_millis_duration()
+    }
+}
+
+impl From<MicrosDuration> for Duration {
+    #[inline]
+    fn from(duration: MicrosDuration) -> Self {
+        Duration::from_micros(duration.0)
+    }
+}
@@ -0,0 +1,74 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MillisDuration, MonotonicClock};
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// A `MonotonicClock` decorator that only reports time in steps of a minimum resolution.
+///
+/// Readings of the inner clock are floored to a multiple of `resolution`. Rapid calls that have
+/// not advanced a full step since the last report get the previously reported value, so the
+/// reported time never decreases.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{Millis, MillisDuration, MinResolutionClock, MonotonicClock};
+/// struct FixedClock;
+///
+/// impl MonotonicClock for FixedClock {
+///     fn now(&self) -> Millis {
+///         Millis::new(1234)
+///     }
+/// }
+///
+/// let clock = MinResolutionClock::new(FixedClock, MillisDuration::from_millis(100));
+/// assert_eq!(clock.now(), Millis::new(1200));
+/// ```
+#[derive(Debug)]
+pub struct MinResolutionClock<C> {
+    inner: C,
+    resolution: MillisDuration,
+    last: AtomicU64,
+}
+
+impl<C: MonotonicClock> MinResolutionClock<C> {
+    /// Wraps `inner` so that it reports time in steps of `resolution`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `resolution` is zero.
+    pub fn new(inner: C, resolution: MillisDuration) -> Self {
+        assert!(
+            resolution.as_millis() > 0,
+            "MinResolutionClock resolution must be non-zero"
+        );
+        Self {
+            inner,
+            resolution,
+            last: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the resolution of the reported time.
+    #[inline]
+    pub fn resolution(&self) -> MillisDuration {
+        self.resolution
+    }
+
+    /// Returns a reference to the wrapped clock.
+    #[inline]
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: MonotonicClock> MonotonicClock for MinResolutionClock<C> {
+    fn now(&self) -> Millis {
+        let raw = self.inner.now().absolute_milliseconds();
+        let floored = raw - raw % self.resolution.as_millis();
+        let previous = self.last.fetch_max(floored, Ordering::Relaxed);
+        Millis::new(previous.max(floored))
+    }
+}
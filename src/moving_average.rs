@@ -0,0 +1,104 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::MillisDuration;
+use alloc::collections::VecDeque;
+
+/// Averages the most recent `MillisDuration` samples over a sliding window.
+///
+/// A running sum is kept, so [`MovingAverageDuration::average`] is O(1). When the window is
+/// full, pushing a sample evicts the oldest one.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{MillisDuration, MovingAverageDuration};
+/// let mut ticks = MovingAverageDuration::new(2);
+/// ticks.push(MillisDuration::from_millis(10));
+/// ticks.push(MillisDuration::from_millis(20));
+/// ticks.push(MillisDuration::from_millis(40));
+/// assert_eq!(ticks.average(), Some(MillisDuration::from_millis(30)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MovingAverageDuration {
+    capacity: usize,
+    samples: VecDeque<MillisDuration>,
+    sum: u128,
+}
+
+impl MovingAverageDuration {
+    /// Creates an empty window that averages at most `capacity` samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "MovingAverageDuration capacity must be non-zero"
+        );
+        Self {
+            capacity,
+            samples: VecDeque::with_capacity(capacity),
+            sum: 0,
+        }
+    }
+
+    /// Returns the maximum number of samples in the window.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of samples currently in the window.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns `true` if no samples have been pushed.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Adds a sample, evicting the oldest one if the window is full.
+    pub fn push(&mut self, sample: MillisDuration) {
+        if self.samples.len() == self.capacity {
+            if let Some(oldest) = self.samples.pop_front() {
+                self.sum -= u128::from(oldest.as_millis());
+            }
+        }
+        self.sum += u128::from(sample.as_millis());
+        self.samples.push_back(sample);
+    }
+
+    /// Returns the mean of the samples in the window, rounded to the nearest millisecond.
+    ///
+    /// Returns `None` if the window is empty.
+    pub fn average(&self) -> Option<MillisDuration> {
+        let len = self.samples.len() as u128;
+        if len == 0 {
+            return None;
+        }
+        let average = (self.sum + len / 2) / len;
+        Some(MillisDuration::from_millis(average as u64))
+    }
+
+    /// Returns the shortest sample in the window.
+    pub fn min(&self) -> Option<MillisDuration> {
+        self.samples.iter().min().copied()
+    }
+
+    /// Returns the longest sample in the window.
+    pub fn max(&self) -> Option<MillisDuration> {
+        self.samples.iter().max().copied()
+    }
+
+    /// Removes all samples.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+        self.sum = 0;
+    }
+}
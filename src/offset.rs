@@ -0,0 +1,74 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MillisDuration, MonotonicClock};
+
+/// A `MonotonicClock` decorator that shifts every reading by a fixed offset.
+///
+/// Useful to align replayed readings with the epoch of the original recording. The offset is
+/// applied with saturating arithmetic, so readings clamp at zero and `u64::MAX` instead of
+/// panicking.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{ManualClock, Millis, MillisDuration, MonotonicClock, OffsetClock};
+/// let clock = OffsetClock::new(ManualClock::new(Millis::new(500)), MillisDuration::from_millis(1000));
+/// assert_eq!(clock.now(), Millis::new(1500));
+/// ```
+#[derive(Debug)]
+pub struct OffsetClock<C> {
+    inner: C,
+    offset: MillisDuration,
+    behind: bool,
+}
+
+impl<C: MonotonicClock> OffsetClock<C> {
+    /// Wraps `inner` so that every reading is `offset` later.
+    pub fn new(inner: C, offset: MillisDuration) -> Self {
+        Self {
+            inner,
+            offset,
+            behind: false,
+        }
+    }
+
+    /// Wraps `inner` so that every reading is `offset` earlier.
+    pub fn behind(inner: C, offset: MillisDuration) -> Self {
+        Self {
+            inner,
+            offset,
+            behind: true,
+        }
+    }
+
+    /// Returns the size of the offset.
+    #[inline]
+    pub fn offset(&self) -> MillisDuration {
+        self.offset
+    }
+
+    /// Returns `true` if the offset is subtracted from the readings.
+    #[inline]
+    pub fn is_behind(&self) -> bool {
+        self.behind
+    }
+
+    /// Returns a reference to the wrapped clock.
+    #[inline]
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: MonotonicClock> MonotonicClock for OffsetClock<C> {
+    fn now(&self) -> Millis {
+        let now = self.inner.now();
+        if self.behind {
+            now.saturating_sub(self.offset)
+        } else {
+            now.saturating_add(self.offset)
+        }
+    }
+}
@@ -0,0 +1,78 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MillisLow16};
+
+/// Tracks a peer's clock by reconstructing the full timestamps of received lower bits.
+///
+/// The first reconstruction uses [`Millis::from_lower`] relative to the local time. After that,
+/// the last reconstructed peer time is advanced by the local time that has passed since it was
+/// received, and the lower bits are resolved to the timestamp closest to that estimate. This
+/// keeps reconstruction unambiguous over gaps far longer than the 3000 ms tolerated by
+/// `from_lower`, as long as the estimate drifts less than half a 16-bit wrap (about 32 seconds).
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{Millis, PeerClockTracker};
+/// let mut tracker = PeerClockTracker::new();
+/// let sent = Millis::new(0x1_fff0);
+/// let reconstructed = tracker.reconstruct(sent.to_lower(), Millis::new(0x1_fff4));
+/// assert_eq!(reconstructed, Some(sent));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PeerClockTracker {
+    last: Option<(Millis, Millis)>,
+}
+
+impl PeerClockTracker {
+    /// Creates a tracker without any peer estimate.
+    pub fn new() -> Self {
+        Self { last: None }
+    }
+
+    /// Returns the latest reconstructed peer timestamp, if any.
+    pub fn last_reconstructed(&self) -> Option<Millis> {
+        self.last.map(|(peer, _)| peer)
+    }
+
+    /// Estimates the current peer time from the last reconstruction and the local time.
+    pub fn estimate(&self, local_now: Millis) -> Option<Millis> {
+        self.last.map(|(peer, local)| {
+            Millis::new(
+                peer.absolute_milliseconds()
+                    + local_now
+                        .absolute_milliseconds()
+                        .saturating_sub(local.absolute_milliseconds()),
+            )
+        })
+    }
+
+    /// Reconstructs the full peer timestamp from `lower` bits received at `local_now`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Millis)` - The reconstructed peer timestamp.
+    /// * `None` - If there is no estimate yet and `local_now.from_lower(lower)` fails, or if the
+    ///   reconstructed time would be before the epoch.
+    pub fn reconstruct(&mut self, lower: MillisLow16, local_now: Millis) -> Option<Millis> {
+        let reconstructed = match self.estimate(local_now) {
+            None => local_now.from_lower(lower)?,
+            Some(expected) => {
+                let expected = expected.absolute_milliseconds();
+                let diff = lower.wrapping_sub(expected as u16) as i16;
+                Millis::new(expected.checked_add_signed(diff as i64)?)
+            }
+        };
+
+        if self
+            .last_reconstructed()
+            .is_none_or(|last| reconstructed > last)
+        {
+            self.last = Some((reconstructed, local_now));
+        }
+
+        Some(reconstructed)
+    }
+}
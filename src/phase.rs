@@ -0,0 +1,55 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{float, MillisDuration};
+
+/// Accumulates the phase of an oscillator from elapsed time.
+///
+/// The phase is kept in cycles, wrapped into `[0, 1)`.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{MillisDuration, PhaseAccumulator};
+/// let mut oscillator = PhaseAccumulator::new(2.0);
+/// assert_eq!(oscillator.advance(MillisDuration::from_millis(125)), 0.25);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhaseAccumulator {
+    freq_hz: f32,
+    phase: f64,
+}
+
+impl PhaseAccumulator {
+    /// Creates an oscillator with frequency `freq_hz`, starting at phase zero.
+    pub fn new(freq_hz: f32) -> Self {
+        Self {
+            freq_hz,
+            phase: 0.0,
+        }
+    }
+
+    /// Returns the frequency in hertz.
+    #[inline]
+    pub fn freq_hz(&self) -> f32 {
+        self.freq_hz
+    }
+
+    /// Changes the frequency without affecting the current phase.
+    pub fn set_freq_hz(&mut self, freq_hz: f32) {
+        self.freq_hz = freq_hz;
+    }
+
+    /// Returns the current phase in `[0, 1)`.
+    #[inline]
+    pub fn phase(&self) -> f64 {
+        self.phase
+    }
+
+    /// Advances the phase by `freq_hz * dt` and returns the wrapped result.
+    pub fn advance(&mut self, dt: MillisDuration) -> f64 {
+        self.phase = float::rem_euclid(self.phase + self.freq_hz as f64 * dt.as_secs_f64(), 1.0);
+        self.phase
+    }
+}
@@ -0,0 +1,57 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::MillisDuration;
+
+/// Accumulates a time-scaled error for the integral term of a PID controller.
+///
+/// Each step adds `error * dt` (with `dt` in seconds) to the integral, which is then clamped to
+/// `[-limit, limit]` to prevent integral windup.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{MillisDuration, PidTimeIntegrator};
+/// let mut integrator = PidTimeIntegrator::new(10.0);
+/// integrator.integrate(2.0, MillisDuration::from_millis(500));
+/// assert_eq!(integrator.integral(), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PidTimeIntegrator {
+    integral: f64,
+    limit: f64,
+}
+
+impl PidTimeIntegrator {
+    /// Creates an integrator starting at zero, clamped to `[-limit, limit]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is negative or NaN.
+    pub fn new(limit: f64) -> Self {
+        assert!(limit >= 0.0, "PidTimeIntegrator limit must be non-negative");
+        Self {
+            integral: 0.0,
+            limit,
+        }
+    }
+
+    /// Adds `error` scaled by `dt` to the integral and returns the clamped result.
+    pub fn integrate(&mut self, error: f32, dt: MillisDuration) -> f64 {
+        self.integral =
+            (self.integral + error as f64 * dt.as_secs_f64()).clamp(-self.limit, self.limit);
+        self.integral
+    }
+
+    /// Returns the current integral.
+    #[inline]
+    pub fn integral(&self) -> f64 {
+        self.integral
+    }
+
+    /// Resets the integral to zero.
+    pub fn reset(&mut self) {
+        self.integral = 0.0;
+    }
+}
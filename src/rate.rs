@@ -0,0 +1,66 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::MillisDuration;
+
+/// A frequency in Hz, such as the tick rate of a game loop.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{MillisDuration, Rate};
+/// let tick_rate = Rate::from_hz(50.0).unwrap();
+/// assert_eq!(tick_rate.period(), MillisDuration::from_millis(20));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Rate(f32);
+
+impl Rate {
+    /// Creates a `Rate` from a frequency in Hz.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `hz` is NaN, infinite, zero or negative, since those have no
+    /// meaningful period.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::Rate;
+    /// assert!(Rate::from_hz(60.0).is_ok());
+    /// assert!(Rate::from_hz(0.0).is_err());
+    /// assert!(Rate::from_hz(-30.0).is_err());
+    /// ```
+    pub fn from_hz(hz: f32) -> Result<Self, &'static str> {
+        if !hz.is_finite() {
+            return Err("must be a number");
+        }
+        if hz <= 0.0 {
+            return Err("must be a positive value");
+        }
+        Ok(Self(hz))
+    }
+
+    /// Returns the number of occurrences per second.
+    #[inline]
+    pub fn per_second(&self) -> f32 {
+        self.0
+    }
+
+    /// Returns the time between two occurrences, truncated to whole milliseconds.
+    ///
+    /// Truncation matches [`MillisDuration::FRAME_60HZ`], so 60 Hz gives 16 ms. Rates above
+    /// 1000 Hz give a zero period, and very low rates saturate at `u64::MAX` milliseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{MillisDuration, Rate};
+    /// let period = Rate::from_hz(60.0).unwrap().period();
+    /// assert_eq!(period, MillisDuration::FRAME_60HZ);
+    /// ```
+    pub fn period(&self) -> MillisDuration {
+        MillisDuration::from_millis((1000.0 / f64::from(self.0)) as u64)
+    }
+}
@@ -0,0 +1,105 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MonotonicClock};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::vec::Vec;
+
+/// A `MonotonicClock` decorator that records every reading of the inner clock.
+///
+/// The recording can be replayed with a [`PlaybackClock`]. With the `serde` feature, the
+/// recorded `Vec<Millis>` can be persisted and loaded with any serde format.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{InstantMonotonicClock, MonotonicClock, PlaybackClock, RecordingClock};
+/// let clock = RecordingClock::new(InstantMonotonicClock::new());
+/// let first = clock.now();
+/// let second = clock.now();
+///
+/// let playback = PlaybackClock::new(clock.recording());
+/// assert_eq!(playback.now(), first);
+/// assert_eq!(playback.now(), second);
+/// ```
+#[derive(Debug)]
+pub struct RecordingClock<C> {
+    inner: C,
+    readings: Mutex<Vec<Millis>>,
+}
+
+impl<C: MonotonicClock> RecordingClock<C> {
+    /// Wraps `inner` with an empty recording.
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            readings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Returns a copy of the readings recorded so far.
+    pub fn recording(&self) -> Vec<Millis> {
+        self.readings.lock().unwrap().clone()
+    }
+
+    /// Consumes the clock and returns the recorded readings.
+    pub fn into_recording(self) -> Vec<Millis> {
+        self.readings.into_inner().unwrap()
+    }
+
+    /// Returns a reference to the wrapped clock.
+    #[inline]
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: MonotonicClock> MonotonicClock for RecordingClock<C> {
+    fn now(&self) -> Millis {
+        let now = self.inner.now();
+        self.readings.lock().unwrap().push(now);
+        now
+    }
+}
+
+/// A `MonotonicClock` that replays a recorded sequence of readings.
+///
+/// Each call to `now()` returns the next recorded reading. Once the recording is exhausted,
+/// `now_checked()` returns `None` and `now()` panics, so a replay that diverges from the
+/// recording is noticed.
+#[derive(Debug)]
+pub struct PlaybackClock {
+    readings: Vec<Millis>,
+    position: AtomicUsize,
+}
+
+impl PlaybackClock {
+    /// Creates a clock replaying `readings` from the start.
+    pub fn new(readings: Vec<Millis>) -> Self {
+        Self {
+            readings,
+            position: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the number of readings that have not been replayed yet.
+    pub fn remaining(&self) -> usize {
+        self.readings
+            .len()
+            .saturating_sub(self.position.load(Ordering::Relaxed))
+    }
+}
+
+impl MonotonicClock for PlaybackClock {
+    fn now(&self) -> Millis {
+        self.now_checked()
+            .expect("PlaybackClock recording is exhausted")
+    }
+
+    fn now_checked(&self) -> Option<Millis> {
+        let position = self.position.fetch_add(1, Ordering::Relaxed);
+        self.readings.get(position).copied()
+    }
+}
@@ -0,0 +1,99 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MillisDuration};
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// Keeps the most recent `Millis` readings, evicting the oldest when full.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{Millis, TimestampRing};
+/// let mut ring = TimestampRing::new(2);
+/// ring.push(Millis::new(10));
+/// ring.push(Millis::new(20));
+/// ring.push(Millis::new(30));
+/// assert_eq!(ring.recent(2), vec![Millis::new(20), Millis::new(30)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TimestampRing {
+    capacity: usize,
+    timestamps: VecDeque<Millis>,
+}
+
+impl TimestampRing {
+    /// Creates an empty ring that holds at most `capacity` timestamps.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "TimestampRing capacity must be non-zero");
+        Self {
+            capacity,
+            timestamps: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the maximum number of timestamps kept.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of timestamps currently stored.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.timestamps.len()
+    }
+
+    /// Returns `true` if no timestamps are stored.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.timestamps.is_empty()
+    }
+
+    /// Stores a timestamp, evicting the oldest one if the ring is full.
+    pub fn push(&mut self, timestamp: Millis) {
+        if self.timestamps.len() == self.capacity {
+            self.timestamps.pop_front();
+        }
+        self.timestamps.push_back(timestamp);
+    }
+
+    /// Returns up to `n` of the most recent timestamps, oldest first.
+    pub fn recent(&self, n: usize) -> Vec<Millis> {
+        let skip = self.timestamps.len().saturating_sub(n);
+        self.timestamps.iter().skip(skip).copied().collect()
+    }
+
+    /// Computes the durations between each pair of consecutive stored timestamps, oldest first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a stored timestamp is earlier than the one pushed before it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{Millis, MillisDuration, TimestampRing};
+    /// let mut ring = TimestampRing::new(4);
+    /// ring.push(Millis::new(100));
+    /// ring.push(Millis::new(150));
+    /// ring.push(Millis::new(230));
+    /// assert_eq!(
+    ///     ring.inter_arrival(),
+    ///     vec![MillisDuration::from_millis(50), MillisDuration::from_millis(80)]
+    /// );
+    /// ```
+    pub fn inter_arrival(&self) -> Vec<MillisDuration> {
+        self.timestamps
+            .iter()
+            .zip(self.timestamps.iter().skip(1))
+            .map(|(earlier, later)| *later - *earlier)
+            .collect()
+    }
+}
@@ -0,0 +1,82 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MillisDuration, MonotonicClock};
+
+/// A `MonotonicClock` decorator that runs faster or slower than the inner clock.
+///
+/// The inner clock's reading at construction is the baseline. `now()` reports the baseline plus
+/// the inner time elapsed since then, multiplied by `scale`. A scale of `0.0` freezes the clock
+/// at the baseline, and `0.5` runs it at half speed.
+///
+/// The scaled time is computed in `f64` and truncated to whole milliseconds, so it stays exact
+/// for elapsed times up to about 2^53 ms. The `f32` scale itself is only exact to about seven
+/// significant digits, so a scale like `1.1` is really `1.10000002384...` and drifts by that
+/// fraction over long runs.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{ManualClock, Millis, MillisDuration, MonotonicClock, ScaledClock};
+/// let clock = ScaledClock::new(ManualClock::new(Millis::new(100)), 2.0);
+/// clock.inner().advance(MillisDuration::from_millis(1000));
+/// assert_eq!(clock.now(), Millis::new(2100));
+/// ```
+#[derive(Debug)]
+pub struct ScaledClock<C> {
+    inner: C,
+    scale: f32,
+    baseline: Millis,
+}
+
+impl<C: MonotonicClock> ScaledClock<C> {
+    /// Wraps `inner`, scaling the time elapsed from now on by `scale`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is negative, NaN or infinite.
+    pub fn new(inner: C, scale: f32) -> Self {
+        assert!(
+            scale.is_finite() && scale >= 0.0,
+            "ScaledClock scale must be finite and non-negative"
+        );
+        let baseline = inner.now();
+        Self {
+            inner,
+            scale,
+            baseline,
+        }
+    }
+
+    /// Returns the scale factor.
+    #[inline]
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Returns the inner clock's reading at construction.
+    #[inline]
+    pub fn baseline(&self) -> Millis {
+        self.baseline
+    }
+
+    /// Returns a reference to the wrapped clock.
+    #[inline]
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: MonotonicClock> MonotonicClock for ScaledClock<C> {
+    fn now(&self) -> Millis {
+        let elapsed = self
+            .inner
+            .now()
+            .checked_duration_since_ms(self.baseline)
+            .unwrap_or_default();
+        let scaled = elapsed.as_millis() as f64 * f64::from(self.scale);
+        self.baseline
+            .saturating_add(MillisDuration::from_millis(scaled as u64))
+    }
+}
@@ -0,0 +1,87 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{float, MillisDuration};
+#[cfg(feature = "serde_human")]
+use alloc::{format, string::String, string::ToString};
+use core::fmt;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes as the plain millisecond count. With the `serde_human` feature, human-readable
+/// formats get a unit string such as `"4s"` instead, while compact formats keep the `u64`.
+impl Serialize for MillisDuration {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[cfg(feature = "serde_human")]
+        if serializer.is_human_readable() {
+            return serializer.serialize_str(&self.to_unit_string());
+        }
+        serializer.serialize_u64(self.as_millis())
+    }
+}
+
+/// Accepts an integer millisecond count, or a float that is rounded to the nearest millisecond
+/// (`1500.4` becomes `1500`). Negative and non-finite values are rejected.
+impl<'de> Deserialize<'de> for MillisDuration {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[cfg(feature = "serde_human")]
+        if deserializer.is_human_readable() {
+            return deserializer.deserialize_any(MillisDurationVisitor);
+        }
+        deserializer.deserialize_u64(MillisDurationVisitor)
+    }
+}
+
+struct MillisDurationVisitor;
+
+impl Visitor<'_> for MillisDurationVisitor {
+    type Value = MillisDuration;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(feature = "serde_human")]
+        return formatter
+            .write_str("a non-negative millisecond count or a duration string like \"4s\"");
+        #[cfg(not(feature = "serde_human"))]
+        formatter.write_str("a non-negative millisecond count")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(MillisDuration::from_millis(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        u64::try_from(value)
+            .map(MillisDuration::from_millis)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        let rounded = float::round(value);
+        // `u64::MAX as f64` rounds up to 2^64, which itself is out of range.
+        if !(0.0..u64::MAX as f64).contains(&rounded) {
+            return Err(E::invalid_value(de::Unexpected::Float(value), &self));
+        }
+        Ok(MillisDuration::from_millis(rounded as u64))
+    }
+
+    #[cfg(feature = "serde_human")]
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value.parse().map_err(E::custom)
+    }
+}
+
+#[cfg(feature = "serde_human")]
+impl MillisDuration {
+    /// Formats the duration with the largest unit that represents it exactly.
+    fn to_unit_string(self) -> String {
+        let millis = self.as_millis();
+        match millis {
+            0 => "0ms".to_string(),
+            _ if millis.is_multiple_of(3_600_000) => format!("{}h", millis / 3_600_000),
+            _ if millis.is_multiple_of(60_000) => format!("{}m", millis / 60_000),
+            _ if millis.is_multiple_of(1000) => format!("{}s", millis / 1000),
+            _ => format!("{millis}ms"),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::MillisDuration;
+use core::fmt;
+use core::ops::Neg;
+
+/// Represents a duration in milliseconds that may be negative.
+///
+/// Useful for offsets and skew corrections, where the difference between two timestamps can go
+/// either way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SignedMillisDuration(i64);
+
+impl SignedMillisDuration {
+    /// Creates a new `SignedMillisDuration` from milliseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::SignedMillisDuration;
+    /// let offset = SignedMillisDuration::from_millis(-250);
+    /// assert_eq!(offset.as_millis(), -250);
+    /// ```
+    #[inline]
+    pub const fn from_millis(millis: i64) -> Self {
+        Self(millis)
+    }
+
+    /// Returns the duration in milliseconds.
+    #[inline]
+    pub const fn as_millis(&self) -> i64 {
+        self.0
+    }
+
+    /// Returns `true` if the duration is negative.
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.0 < 0
+    }
+
+    /// Returns the magnitude of the duration as an unsigned `MillisDuration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{MillisDuration, SignedMillisDuration};
+    /// let offset = SignedMillisDuration::from_millis(-250);
+    /// assert_eq!(offset.unsigned_abs(), MillisDuration::from_millis(250));
+    /// ```
+    #[inline]
+    pub const fn unsigned_abs(&self) -> MillisDuration {
+        MillisDuration::from_millis(self.0.unsigned_abs())
+    }
+}
+
+impl fmt::Display for SignedMillisDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ms", self.0)
+    }
+}
+
+impl Neg for SignedMillisDuration {
+    type Output = SignedMillisDuration;
+
+    #[inline]
+    fn neg(self) -> SignedMillisDuration {
+        Self(
+            self.0
+                .checked_neg()
+                .expect("overflow on neg signedmillisduration"),
+        )
+    }
+}
+
+/// Negates a `MillisDuration` into a `SignedMillisDuration`.
+///
+/// # Panics
+///
+/// Panics if the duration is larger than `i64::MAX` milliseconds.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{MillisDuration, SignedMillisDuration};
+/// let duration = MillisDuration::from_millis(500);
+/// assert_eq!(-duration, SignedMillisDuration::from_millis(-500));
+/// ```
+impl Neg for MillisDuration {
+    type Output = SignedMillisDuration;
+
+    #[inline]
+    fn neg(self) -> SignedMillisDuration {
+        let millis = i64::try_from(self.as_millis()).expect("overflow on neg millisduration");
+        SignedMillisDuration(-millis)
+    }
+}
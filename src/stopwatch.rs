@@ -0,0 +1,70 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MillisDuration, MonotonicClock};
+
+/// Measures elapsed time and laps, reading the clock internally.
+///
+/// [`Stopwatch::elapsed`] measures from the start, while [`Stopwatch::lap`] measures from the
+/// previous lap. [`Stopwatch::restart`] resets both. If the clock reports an earlier time than
+/// the start of the measurement, for example after being set back, the result is zero.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{ManualClock, Millis, MillisDuration, Stopwatch};
+/// let clock = ManualClock::new(Millis::new(0));
+/// let mut stopwatch = Stopwatch::start(&clock);
+/// clock.advance(MillisDuration::from_millis(30));
+/// assert_eq!(stopwatch.lap(), MillisDuration::from_millis(30));
+/// clock.advance(MillisDuration::from_millis(20));
+/// assert_eq!(stopwatch.lap(), MillisDuration::from_millis(20));
+/// assert_eq!(stopwatch.elapsed(), MillisDuration::from_millis(50));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Stopwatch<'a, C: MonotonicClock> {
+    clock: &'a C,
+    start: Millis,
+    lap_start: Millis,
+}
+
+impl<'a, C: MonotonicClock> Stopwatch<'a, C> {
+    /// Starts a stopwatch, reading the start time from `clock`.
+    pub fn start(clock: &'a C) -> Self {
+        let now = clock.now();
+        Self {
+            clock,
+            start: now,
+            lap_start: now,
+        }
+    }
+
+    /// Returns the time elapsed since the stopwatch was started or restarted.
+    pub fn elapsed(&self) -> MillisDuration {
+        Self::saturating_since(self.clock.now(), self.start)
+    }
+
+    /// Returns the time since the previous lap, or since the start for the first lap, and
+    /// starts the next lap.
+    pub fn lap(&mut self) -> MillisDuration {
+        let now = self.clock.now();
+        let lap = Self::saturating_since(now, self.lap_start);
+        self.lap_start = now;
+        lap
+    }
+
+    /// Starts over from the current time.
+    pub fn restart(&mut self) {
+        let now = self.clock.now();
+        self.start = now;
+        self.lap_start = now;
+    }
+
+    fn saturating_since(now: Millis, earlier: Millis) -> MillisDuration {
+        MillisDuration::from_millis(
+            now.absolute_milliseconds()
+                .saturating_sub(earlier.absolute_milliseconds()),
+        )
+    }
+}
@@ -0,0 +1,72 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MonotonicClock};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A `MonotonicClock` for platforms that have `SystemTime` but no `Instant`.
+///
+/// `now()` returns the milliseconds elapsed since construction, based on the largest system time
+/// seen so far. If the system clock jumps backwards, the reported time holds still until the
+/// system clock has caught up again, so it never decreases.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{MonotonicClock, SystemTimeMonotonicClock};
+/// let clock = SystemTimeMonotonicClock::new();
+/// let first = clock.now();
+/// assert!(clock.now() >= first);
+/// ```
+#[derive(Debug)]
+pub struct SystemTimeMonotonicClock<F = fn() -> SystemTime> {
+    time_source: F,
+    started: u64,
+    max_seen: AtomicU64,
+}
+
+impl SystemTimeMonotonicClock {
+    /// Creates a clock reading `SystemTime::now()`.
+    pub fn new() -> Self {
+        Self::with_time_source(SystemTime::now)
+    }
+}
+
+impl Default for SystemTimeMonotonicClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Fn() -> SystemTime> SystemTimeMonotonicClock<F> {
+    /// Creates a clock reading the system time from `time_source`.
+    ///
+    /// This is mostly useful for simulating system clock adjustments in tests.
+    pub fn with_time_source(time_source: F) -> Self {
+        let started = unix_millis(time_source());
+        Self {
+            time_source,
+            started,
+            max_seen: AtomicU64::new(started),
+        }
+    }
+}
+
+impl<F: Fn() -> SystemTime> MonotonicClock for SystemTimeMonotonicClock<F> {
+    fn now(&self) -> Millis {
+        let current = unix_millis((self.time_source)());
+        let max_seen = self
+            .max_seen
+            .fetch_max(current, Ordering::Relaxed)
+            .max(current);
+        Millis::new(max_seen - self.started)
+    }
+}
+
+/// Times before the Unix epoch are treated as the epoch itself.
+fn unix_millis(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |since_epoch| since_epoch.as_millis() as u64)
+}
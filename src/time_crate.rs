@@ -0,0 +1,56 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MonotonicClock};
+use std::time::Instant;
+use time::ext::InstantExt;
+
+/// A `MonotonicClock` measured with the `time` crate, for projects that already use it.
+///
+/// `time::Instant` is deprecated, so this follows the `time` crate's recommendation of a
+/// `std::time::Instant` together with `time::ext::InstantExt`. [`TimeCrateClock::elapsed`]
+/// returns a `time::Duration`, while `now()` reports the same elapsed time as `Millis`.
+///
+/// Only available with the `time` feature.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{MonotonicClock, TimeCrateClock};
+/// let clock = TimeCrateClock::new();
+/// let first = clock.now();
+/// assert!(clock.now() >= first);
+/// assert!(!clock.elapsed().is_negative());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TimeCrateClock {
+    started: Instant,
+}
+
+impl TimeCrateClock {
+    /// Creates a clock that starts at zero now.
+    pub fn new() -> Self {
+        Self {
+            started: Instant::now(),
+        }
+    }
+
+    /// Returns the time elapsed since the clock was created, as a `time::Duration`.
+    pub fn elapsed(&self) -> time::Duration {
+        Instant::now().signed_duration_since(self.started)
+    }
+}
+
+impl Default for TimeCrateClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MonotonicClock for TimeCrateClock {
+    fn now(&self) -> Millis {
+        let millis = self.elapsed().whole_milliseconds();
+        Millis::new(u64::try_from(millis).unwrap_or_default())
+    }
+}
@@ -0,0 +1,93 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MonotonicClock};
+
+/// Generates increasing `u64` IDs from a clock and a per-millisecond counter.
+///
+/// Each ID holds the timestamp in the upper 52 bits and a counter in the lower
+/// [`TimeIdGenerator::COUNTER_BITS`] bits. The counter restarts at zero when the millisecond
+/// changes. If it runs out within one millisecond, `next` spins on the clock until the next
+/// millisecond. If the clock goes backwards, the last timestamp is kept, so IDs still increase.
+///
+/// Timestamps must fit in 52 bits, see [`TimeIdGenerator::MAX_TIMESTAMP_MILLIS`]. That is
+/// over 140 000 years from the clock's epoch, but a clock with a large base can exceed it.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{InstantMonotonicClock, TimeIdGenerator};
+/// let clock = InstantMonotonicClock::new();
+/// let mut generator = TimeIdGenerator::new();
+/// let first = generator.next(&clock);
+/// assert!(generator.next(&clock) > first);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct TimeIdGenerator {
+    last: Option<Millis>,
+    counter: u64,
+}
+
+impl TimeIdGenerator {
+    /// The number of low bits used for the per-millisecond counter.
+    pub const COUNTER_BITS: u32 = 12;
+
+    /// The largest timestamp, in milliseconds, that fits next to the counter.
+    pub const MAX_TIMESTAMP_MILLIS: u64 = u64::MAX >> Self::COUNTER_BITS;
+
+    const COUNTER_MASK: u64 = (1 << Self::COUNTER_BITS) - 1;
+
+    /// Creates a generator that has not produced any IDs yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the next ID, reading the time from `clock`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock reports a timestamp above
+    /// [`TimeIdGenerator::MAX_TIMESTAMP_MILLIS`], since the ID could no longer increase.
+    pub fn next(&mut self, clock: &impl MonotonicClock) -> u64 {
+        let mut now = clock.now();
+        if let Some(last) = self.last {
+            if now <= last {
+                if self.counter < Self::COUNTER_MASK {
+                    self.counter += 1;
+                    return Self::pack(last, self.counter);
+                }
+                while now <= last {
+                    now = clock.now();
+                }
+            }
+        }
+        assert!(
+            now.absolute_milliseconds() <= Self::MAX_TIMESTAMP_MILLIS,
+            "TimeIdGenerator timestamp does not fit in 52 bits"
+        );
+        self.last = Some(now);
+        self.counter = 0;
+        Self::pack(now, 0)
+    }
+
+    /// Splits an ID into its timestamp, truncated to 52 bits, and counter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use monotonic_time_rs::{Millis, TimeIdGenerator};
+    /// assert_eq!(TimeIdGenerator::split((1000 << 12) | 3), (Millis::new(1000), 3));
+    /// ```
+    pub fn split(id: u64) -> (Millis, u64) {
+        (
+            Millis::new(id >> Self::COUNTER_BITS),
+            id & Self::COUNTER_MASK,
+        )
+    }
+
+    fn pack(timestamp: Millis, counter: u64) -> u64 {
+        debug_assert!(timestamp.absolute_milliseconds() <= Self::MAX_TIMESTAMP_MILLIS);
+        (timestamp.absolute_milliseconds() << Self::COUNTER_BITS) | counter
+    }
+}
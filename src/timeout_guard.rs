@@ -0,0 +1,57 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MillisDuration, MonotonicClock};
+
+/// Calls a callback on drop if the guarded scope took longer than a threshold.
+///
+/// The start time is read from the clock when the guard is created. When the guard is dropped,
+/// the elapsed time is computed and `on_exceed` is called with it if it is over `threshold`.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{InstantMonotonicClock, MillisDuration, TimeoutGuard};
+/// let clock = InstantMonotonicClock::new();
+/// {
+///     let _guard = TimeoutGuard::new(&clock, MillisDuration::from_millis(100), |elapsed| {
+///         eprintln!("slow section took {elapsed}");
+///     });
+///     // work that should finish within 100 ms
+/// }
+/// ```
+pub struct TimeoutGuard<'a, C: MonotonicClock, F: FnOnce(MillisDuration)> {
+    clock: &'a C,
+    start: Millis,
+    threshold: MillisDuration,
+    on_exceed: Option<F>,
+}
+
+impl<'a, C: MonotonicClock, F: FnOnce(MillisDuration)> TimeoutGuard<'a, C, F> {
+    /// Starts guarding a scope, reading the start time from `clock`.
+    pub fn new(clock: &'a C, threshold: MillisDuration, on_exceed: F) -> Self {
+        Self {
+            clock,
+            start: clock.now(),
+            threshold,
+            on_exceed: Some(on_exceed),
+        }
+    }
+
+    /// Returns the time elapsed since the guard was created.
+    pub fn elapsed(&self) -> MillisDuration {
+        self.clock.now() - self.start
+    }
+}
+
+impl<C: MonotonicClock, F: FnOnce(MillisDuration)> Drop for TimeoutGuard<'_, C, F> {
+    fn drop(&mut self) {
+        let elapsed = self.elapsed();
+        if elapsed > self.threshold {
+            if let Some(on_exceed) = self.on_exceed.take() {
+                on_exceed(elapsed);
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use alloc::vec::Vec;
+
+/// Appends `value` as unsigned LEB128, seven bits per byte with the high bit as continuation.
+pub(crate) fn write_u64(mut value: u64, out: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// Reads an unsigned LEB128 value, returning it and the number of bytes consumed.
+///
+/// Returns `None` if the input ends before the last byte or the value does not fit in a `u64`.
+pub(crate) fn read_u64(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut value = 0u64;
+    for (index, byte) in bytes.iter().enumerate() {
+        let shift = index as u32 * 7;
+        let bits = (byte & 0x7f) as u64;
+        if shift >= 64 || (bits << shift) >> shift != bits {
+            return None;
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Some((value, index + 1));
+        }
+    }
+    None
+}
@@ -0,0 +1,60 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MonotonicClock};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// A `MonotonicClock` anchored to Unix time at construction.
+///
+/// At creation, `now()` equals the current Unix time in milliseconds. After that it advances with
+/// `std::time::Instant`, so it never decreases even if the system clock is adjusted; it may
+/// drift away from the system clock over time.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{MonotonicClock, WallAnchoredClock};
+/// let clock = WallAnchoredClock::new();
+/// assert!(clock.now() >= clock.unix_base());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct WallAnchoredClock {
+    started: Instant,
+    unix_base: Millis,
+}
+
+impl WallAnchoredClock {
+    /// Creates a clock anchored to the current system time.
+    ///
+    /// If the system time is before the Unix epoch, the clock is anchored at zero.
+    pub fn new() -> Self {
+        let started = Instant::now();
+        let unix_millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since_epoch| since_epoch.as_millis() as u64);
+        Self {
+            started,
+            unix_base: Millis::new(unix_millis),
+        }
+    }
+
+    /// Returns the Unix time in milliseconds captured at construction.
+    #[inline]
+    pub fn unix_base(&self) -> Millis {
+        self.unix_base
+    }
+}
+
+impl Default for WallAnchoredClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MonotonicClock for WallAnchoredClock {
+    fn now(&self) -> Millis {
+        let elapsed = Instant::now().duration_since(self.started);
+        Millis::new(self.unix_base.absolute_milliseconds() + elapsed.as_millis() as u64)
+    }
+}
@@ -6,6 +6,14 @@ use crate::MonotonicClock;
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 
+#[cfg(target_arch = "wasm32")]
+fn performance() -> web_sys::Performance {
+    web_sys::window()
+        .expect("WasmMonotonicClock requires a Window, WorkerGlobalScope is not supported")
+        .performance()
+        .expect("WasmMonotonicClock requires the Performance API (window.performance)")
+}
+
 #[cfg(target_arch = "wasm32")]
 pub struct WasmMonotonicClock {
     started: f64,
@@ -14,10 +22,9 @@ pub struct WasmMonotonicClock {
 #[cfg(target_arch = "wasm32")]
 impl WasmMonotonicClock {
     pub fn new() -> Self {
-        let window = web_sys::window().expect("should have a Window");
-        let performance = window.performance().expect("should have a Performance");
-        let now = performance.now();
-        Self { started: now }
+        Self {
+            started: performance().now(),
+        }
     }
 }
 
@@ -31,10 +38,7 @@ impl Default for WasmMonotonicClock {
 #[cfg(target_arch = "wasm32")]
 impl MonotonicClock for WasmMonotonicClock {
     fn now(&self) -> Millis {
-        let window = web_sys::window().expect("should have a Window");
-        let performance = window.performance().expect("should have a Performance");
-        let current = performance.now();
-        let elapsed = current - self.started;
+        let elapsed = performance().now() - self.started;
         Millis::new(elapsed as u64)
     }
 }
@@ -0,0 +1,47 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/monotonic-time-rs
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+use crate::{Millis, MonotonicClock};
+use tokio::sync::watch;
+
+/// A `MonotonicClock` that reports the latest `Millis` sent on a `tokio::sync::watch` channel.
+///
+/// The time is driven externally, typically by a ticker task that owns the sender. `now()` never
+/// blocks and does not mark the value as seen. If the sender is dropped, the last sent value is
+/// reported from then on.
+///
+/// Only available with the `tokio` feature.
+///
+/// # Examples
+///
+/// ```
+/// use monotonic_time_rs::{Millis, MonotonicClock, WatchClock};
+/// let (sender, receiver) = tokio::sync::watch::channel(Millis::new(0));
+/// let clock = WatchClock::new(receiver);
+/// sender.send(Millis::new(16)).unwrap();
+/// assert_eq!(clock.now(), Millis::new(16));
+/// ```
+#[derive(Debug, Clone)]
+pub struct WatchClock {
+    receiver: watch::Receiver<Millis>,
+}
+
+impl WatchClock {
+    /// Creates a clock reading the latest value of `receiver`.
+    pub fn new(receiver: watch::Receiver<Millis>) -> Self {
+        Self { receiver }
+    }
+
+    /// Returns a reference to the wrapped receiver.
+    #[inline]
+    pub fn receiver(&self) -> &watch::Receiver<Millis> {
+        &self.receiver
+    }
+}
+
+impl MonotonicClock for WatchClock {
+    fn now(&self) -> Millis {
+        *self.receiver.borrow()
+    }
+}
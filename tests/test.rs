@@ -3,7 +3,17 @@
  * Licensed under the MIT License. See LICENSE in the project root for license information.
  */
 
-use monotonic_time_rs::{InstantMonotonicClock, Millis, MillisDuration, MonotonicClock};
+use monotonic_time_rs::{
+    assert_millis_approx, clock_delta, with_default_clock, ActiveTimeClock, ClampedStepClock,
+    ClockMapping, CountingClock, DebugMonotonicClock, DriftEstimator, DualClock, FaultyClock,
+    FrameClock, FreezableClock, FromLowerError, InstantMonotonicClock, InterpolatingClock,
+    IntervalTimer, LatencyClass, LowTracker, ManualClock, Micros, MicrosDuration, Millis,
+    MillisDuration, MinResolutionClock, MonotonicClock, MovingAverageDuration, OffsetClock,
+    PeerClockTracker, PhaseAccumulator, PidTimeIntegrator, PlaybackClock, Rate, RecordingClock,
+    ScaledClock, SignedMillisDuration, Stopwatch, SystemTimeMonotonicClock, TimeIdGenerator,
+    TimeoutGuard, TimestampRing, WallAnchoredClock,
+};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::{thread::sleep, time::Duration};
 
 #[test_log::test]
@@ -14,7 +24,7 @@ fn realtime_instant() {
     sleep(Duration::from_millis(1000));
     let end_time = t.now();
     let duration = end_time - start_time;
-    assert!(duration > 900.into() && duration < 1100.into());
+    assert!(duration.approx_eq(1000.into(), 100.into()));
 
     let lower = end_time.to_lower();
     let full = end_time.from_lower(lower).expect("expect to work");
@@ -146,3 +156,2237 @@ fn add_assign_durations() {
 
     assert_eq!(duration, MillisDuration::from_millis(3100));
 }
+
+#[test_log::test]
+fn timestamp_ring_push_evicts_oldest() {
+    let mut ring = TimestampRing::new(3);
+    for ms in [10, 20, 30, 40] {
+        ring.push(Millis::new(ms));
+    }
+
+    assert_eq!(ring.len(), 3);
+    assert_eq!(
+        ring.recent(5),
+        vec![Millis::new(20), Millis::new(30), Millis::new(40)]
+    );
+    assert_eq!(ring.recent(1), vec![Millis::new(40)]);
+}
+
+#[test_log::test]
+fn timestamp_ring_inter_arrival() {
+    let mut ring = TimestampRing::new(3);
+    for ms in [100, 116, 133, 150] {
+        ring.push(Millis::new(ms));
+    }
+
+    assert_eq!(
+        ring.inter_arrival(),
+        vec![
+            MillisDuration::from_millis(17),
+            MillisDuration::from_millis(17)
+        ]
+    );
+}
+
+#[test_log::test]
+fn duration_from_env() {
+    std::env::set_var("MONOTONIC_TIME_RS_TEST_FROM_ENV", "1500ms");
+
+    assert_eq!(
+        MillisDuration::from_env("MONOTONIC_TIME_RS_TEST_FROM_ENV"),
+        Ok(Some(MillisDuration::from_millis(1500)))
+    );
+}
+
+#[test_log::test]
+fn duration_from_env_invalid() {
+    std::env::set_var("MONOTONIC_TIME_RS_TEST_FROM_ENV_INVALID", "fast");
+
+    assert!(MillisDuration::from_env("MONOTONIC_TIME_RS_TEST_FROM_ENV_INVALID").is_err());
+    assert_eq!(
+        MillisDuration::from_env("MONOTONIC_TIME_RS_TEST_FROM_ENV_UNSET"),
+        Ok(None)
+    );
+}
+
+struct SequenceClock {
+    readings: Vec<Millis>,
+    index: AtomicUsize,
+}
+
+impl SequenceClock {
+    fn new(readings: &[u64]) -> Self {
+        Self {
+            readings: readings.iter().copied().map(Millis::new).collect(),
+            index: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl MonotonicClock for SequenceClock {
+    fn now(&self) -> Millis {
+        let index = self.index.fetch_add(1, Ordering::SeqCst);
+        self.readings[index.min(self.readings.len() - 1)]
+    }
+}
+
+#[cfg(feature = "async")]
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    use std::task::{Context, Poll, Waker};
+
+    let mut future = std::pin::pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[test_log::test]
+fn time_future() {
+    use monotonic_time_rs::MonotonicClockFutureExt;
+
+    let clock = ManualClock::new(Millis::new(1000));
+
+    let (output, elapsed) = block_on(clock.time_future(async {
+        clock.advance(MillisDuration::from_millis(250));
+        "done"
+    }));
+
+    assert_eq!(output, "done");
+    assert_eq!(elapsed, MillisDuration::from_millis(250));
+}
+
+#[test_log::test]
+fn map_range() {
+    let start = MillisDuration::from_millis(0);
+    let end = MillisDuration::from_millis(1000);
+
+    assert_eq!(
+        MillisDuration::from_millis(500).map_range(start, end, 0.0, 1.0),
+        0.5
+    );
+    assert_eq!(
+        MillisDuration::from_millis(500).map_range(start, end, 10.0, 20.0),
+        15.0
+    );
+}
+
+#[test_log::test]
+fn map_range_clamps() {
+    let start = MillisDuration::from_millis(200);
+    let end = MillisDuration::from_millis(1000);
+
+    assert_eq!(
+        MillisDuration::from_millis(100).map_range(start, end, 0.0, 1.0),
+        0.0
+    );
+    assert_eq!(
+        MillisDuration::from_millis(5000).map_range(start, end, 0.0, 1.0),
+        1.0
+    );
+    assert_eq!(
+        MillisDuration::from_millis(5000).map_range(start, start, 0.0, 1.0),
+        0.0
+    );
+}
+
+#[test_log::test]
+fn peer_clock_tracker_crosses_wrap() {
+    let mut tracker = PeerClockTracker::new();
+    let peer_offset = MillisDuration::from_millis(40);
+
+    for local in [0x2_f000, 0x2_fa00, 0x2_ff00, 0x3_0100, 0x3_0800] {
+        let local_now = Millis::new(local);
+        let sent = local_now - peer_offset;
+        assert_eq!(tracker.reconstruct(sent.to_lower(), local_now), Some(sent));
+    }
+}
+
+#[test_log::test]
+fn peer_clock_tracker_long_gap() {
+    let mut tracker = PeerClockTracker::new();
+    let first = Millis::new(0x4_fc00);
+    assert_eq!(tracker.reconstruct(first.to_lower(), first), Some(first));
+
+    // Twenty seconds without packets, crossing a wrap. Plain `from_lower` gives up here.
+    let local_now = first + MillisDuration::from_millis(20_000);
+    let sent = local_now - MillisDuration::from_millis(5_000);
+    assert_eq!(local_now.from_lower(sent.to_lower()), None);
+    assert_eq!(tracker.reconstruct(sent.to_lower(), local_now), Some(sent));
+    assert_eq!(tracker.last_reconstructed(), Some(sent));
+}
+
+#[test_log::test]
+fn instant_with_base() {
+    let base = Millis::new(1_000_000);
+    let clock = InstantMonotonicClock::with_base(base);
+
+    let now = clock.now();
+    assert!(now >= base);
+    assert!(now - base < MillisDuration::from_millis(1000));
+}
+
+#[test_log::test]
+fn pid_integrates_constant_error() {
+    let mut integrator = PidTimeIntegrator::new(100.0);
+    let dt = MillisDuration::from_millis(250);
+
+    for _ in 0..4 {
+        integrator.integrate(3.0, dt);
+    }
+
+    assert_eq!(integrator.integral(), 3.0);
+}
+
+#[test_log::test]
+fn pid_integral_anti_windup() {
+    let mut integrator = PidTimeIntegrator::new(1.0);
+    let dt = MillisDuration::from_millis(1000);
+
+    integrator.integrate(5.0, dt);
+    assert_eq!(integrator.integral(), 1.0);
+
+    integrator.integrate(-0.5, dt);
+    assert_eq!(integrator.integral(), 0.5);
+
+    integrator.integrate(-10.0, dt);
+    assert_eq!(integrator.integral(), -1.0);
+}
+
+#[test_log::test]
+fn delta_encode_round_trip() {
+    let timestamps: Vec<Millis> = [5000, 5016, 5033, 5050, 6000]
+        .into_iter()
+        .map(Millis::new)
+        .collect();
+
+    let (base, deltas) = Millis::delta_encode(&timestamps);
+
+    assert_eq!(base, Millis::new(5000));
+    assert_eq!(deltas, vec![0, 16, 17, 17, 950]);
+    assert_eq!(Millis::delta_decode(base, &deltas), timestamps);
+}
+
+#[test_log::test]
+fn delta_encode_unsorted_and_empty() {
+    let timestamps = vec![Millis::new(300), Millis::new(100), Millis::new(u64::MAX)];
+    let (base, deltas) = Millis::delta_encode(&timestamps);
+    assert_eq!(Millis::delta_decode(base, &deltas), timestamps);
+
+    let (base, deltas) = Millis::delta_encode(&[]);
+    assert!(deltas.is_empty());
+    assert!(Millis::delta_decode(base, &deltas).is_empty());
+}
+
+#[test_log::test]
+fn checked_mul_f32() {
+    let duration = MillisDuration::from_millis(1000);
+
+    assert_eq!(
+        duration.checked_mul_f32(0.25),
+        Some(MillisDuration::from_millis(250))
+    );
+    assert_eq!(duration.checked_mul_f32(-0.5), None);
+    assert_eq!(duration.checked_mul_f32(f32::NAN), None);
+    assert_eq!(duration.checked_mul_f32(f32::INFINITY), None);
+    assert_eq!(
+        MillisDuration::from_millis(u64::MAX / 2).checked_mul_f32(3.0),
+        None
+    );
+}
+
+#[test_log::test]
+fn checked_mul_f32_is_more_precise_than_operator() {
+    // 2^24 + 1 is the first integer that an `f32` cannot represent.
+    let duration = MillisDuration::from_millis(16_777_217);
+    assert_eq!(
+        duration.checked_mul_f32(1.0),
+        Some(MillisDuration::from_millis(16_777_217))
+    );
+    assert_eq!(duration * 1.0, MillisDuration::from_millis(16_777_216));
+}
+
+#[test_log::test]
+fn min_resolution_clock_coalesces() {
+    let clock = MinResolutionClock::new(
+        ManualClock::new(Millis::new(1005)),
+        MillisDuration::from_millis(10),
+    );
+    assert_eq!(clock.now(), Millis::new(1000));
+
+    clock.inner().advance(MillisDuration::from_millis(4));
+    assert_eq!(clock.now(), Millis::new(1000));
+
+    clock.inner().advance(MillisDuration::from_millis(4));
+    assert_eq!(clock.now(), Millis::new(1010));
+
+    clock.inner().advance(MillisDuration::from_millis(25));
+    assert_eq!(clock.now(), Millis::new(1030));
+}
+
+#[test_log::test]
+fn ratio_of() {
+    assert_eq!(
+        MillisDuration::from_millis(1500).ratio_of(MillisDuration::from_millis(1000)),
+        Some(1.5)
+    );
+    assert_eq!(
+        MillisDuration::from_millis(250).ratio_of(MillisDuration::from_millis(1000)),
+        Some(0.25)
+    );
+    assert_eq!(
+        MillisDuration::from_millis(1500).ratio_of(MillisDuration::from_millis(0)),
+        None
+    );
+}
+
+#[test_log::test]
+fn tick_round_trip() {
+    let tick_duration = MillisDuration::from_millis(50);
+
+    for tick in [0, 1, 7, 1_000_000] {
+        let start = Millis::from_tick(tick, tick_duration);
+        assert_eq!(start.to_tick(tick_duration), tick);
+    }
+}
+
+#[test_log::test]
+fn to_tick_floors_partial_tick() {
+    let tick_duration = MillisDuration::from_millis(50);
+
+    assert_eq!(Millis::new(149).to_tick(tick_duration), 2);
+    assert_eq!(Millis::new(150).to_tick(tick_duration), 3);
+}
+
+#[test_log::test]
+#[should_panic(expected = "zero tick duration")]
+fn to_tick_zero_duration() {
+    Millis::new(100).to_tick(MillisDuration::from_millis(0));
+}
+
+#[test_log::test]
+fn freezable_clock_is_continuous() {
+    let clock = FreezableClock::new(ManualClock::new(Millis::new(1000)));
+
+    clock.inner().advance(MillisDuration::from_millis(100));
+    assert_eq!(clock.now(), Millis::new(1100));
+
+    clock.freeze();
+    clock.inner().advance(MillisDuration::from_millis(500));
+    assert!(clock.is_frozen());
+    assert_eq!(clock.now(), Millis::new(1100));
+
+    clock.thaw();
+    assert_eq!(clock.now(), Millis::new(1100));
+
+    clock.inner().advance(MillisDuration::from_millis(50));
+    assert_eq!(clock.now(), Millis::new(1150));
+
+    clock.freeze();
+    clock.inner().advance(MillisDuration::from_millis(200));
+    clock.thaw();
+    clock.inner().advance(MillisDuration::from_millis(10));
+    assert_eq!(clock.now(), Millis::new(1160));
+}
+
+#[test_log::test]
+fn approx_eq() {
+    let expected = MillisDuration::from_millis(1000);
+    let tolerance = MillisDuration::from_millis(100);
+
+    assert!(MillisDuration::from_millis(1000).approx_eq(expected, tolerance));
+    assert!(MillisDuration::from_millis(900).approx_eq(expected, tolerance));
+    assert!(MillisDuration::from_millis(1100).approx_eq(expected, tolerance));
+    assert!(!MillisDuration::from_millis(899).approx_eq(expected, tolerance));
+    assert!(!MillisDuration::from_millis(1101).approx_eq(expected, tolerance));
+}
+
+#[test_log::test]
+fn reconstruct_from_lower_matches_method() {
+    let window = MillisDuration::from_millis(3000);
+
+    for (now, sent) in [
+        (0x12345678, 0x12345678),
+        (0x12345678, 0x12345000),
+        (0x0002_0100, 0x0001_ff00),
+        (0x0002_0100, 0x0001_0100),
+    ] {
+        let now = Millis::new(now);
+        let lower = Millis::new(sent).to_lower();
+        assert_eq!(
+            Millis::reconstruct_from_lower(now, lower, window),
+            now.from_lower(lower)
+        );
+    }
+}
+
+#[test_log::test]
+fn reconstruct_from_lower_window() {
+    let now = Millis::new(0x0002_0100);
+    let sent = Millis::new(0x0002_0000);
+    let lower = sent.to_lower();
+
+    assert_eq!(
+        Millis::reconstruct_from_lower(now, lower, MillisDuration::from_millis(0x100)),
+        Some(sent)
+    );
+    assert_eq!(
+        Millis::reconstruct_from_lower(now, lower, MillisDuration::from_millis(0xff)),
+        None
+    );
+}
+
+#[test_log::test]
+fn as_secs_rounded() {
+    assert_eq!(MillisDuration::from_millis(1499).as_secs_rounded(), 1);
+    assert_eq!(MillisDuration::from_millis(1500).as_secs_rounded(), 2);
+    assert_eq!(
+        MillisDuration::from_millis(u64::MAX).as_secs_rounded(),
+        u64::MAX / 1000 + 1
+    );
+}
+
+#[test_log::test]
+fn as_secs_floor_and_ceil() {
+    let duration = MillisDuration::from_millis(1500);
+    assert_eq!(duration.as_secs_floor(), 1);
+    assert_eq!(duration.as_secs_ceil(), 2);
+
+    let whole = MillisDuration::from_millis(2000);
+    assert_eq!(whole.as_secs_floor(), 2);
+    assert_eq!(whole.as_secs_ceil(), 2);
+}
+
+#[test_log::test]
+fn debug_clock_passes_through_increasing() {
+    let clock = DebugMonotonicClock::new(SequenceClock::new(&[10, 20, 20, 30]));
+
+    assert_eq!(clock.now(), Millis::new(10));
+    assert_eq!(clock.now(), Millis::new(20));
+    assert_eq!(clock.now(), Millis::new(20));
+    assert_eq!(clock.now(), Millis::new(30));
+}
+
+#[cfg(debug_assertions)]
+#[test_log::test]
+#[should_panic(expected = "non-monotonic clock")]
+fn debug_clock_panics_on_decrease() {
+    let clock = DebugMonotonicClock::new(SequenceClock::new(&[100, 90]));
+
+    clock.now();
+    clock.now();
+}
+
+#[test_log::test]
+fn as_minutes_and_hours() {
+    assert_eq!(MillisDuration::from_millis(90_000).as_minutes_f64(), 1.5);
+    assert_eq!(MillisDuration::from_millis(5_400_000).as_hours_f64(), 1.5);
+    assert_eq!(
+        MillisDuration::from_millis(5_400_000).as_minutes_f64(),
+        90.0
+    );
+}
+
+#[cfg(feature = "step")]
+#[test_log::test]
+fn iterate_millis_range() {
+    let timestamps: Vec<Millis> = (Millis::new(10)..Millis::new(13)).collect();
+    assert_eq!(
+        timestamps,
+        vec![Millis::new(10), Millis::new(11), Millis::new(12)]
+    );
+
+    let durations: Vec<MillisDuration> = (MillisDuration::from_millis(0)
+        ..=MillisDuration::from_millis(100))
+        .step_by(50)
+        .collect();
+    assert_eq!(
+        durations,
+        vec![
+            MillisDuration::from_millis(0),
+            MillisDuration::from_millis(50),
+            MillisDuration::from_millis(100)
+        ]
+    );
+}
+
+#[test_log::test]
+fn varint_round_trip_small() {
+    let mut out = Vec::new();
+    Millis::new(127).write_varint(&mut out);
+    assert_eq!(out.len(), 1);
+    assert_eq!(Millis::read_varint(&out), Some((Millis::new(127), 1)));
+
+    out.clear();
+    MillisDuration::from_millis(16_383).write_varint(&mut out);
+    assert_eq!(out.len(), 2);
+    assert_eq!(
+        MillisDuration::read_varint(&out),
+        Some((MillisDuration::from_millis(16_383), 2))
+    );
+}
+
+#[test_log::test]
+fn varint_round_trip_large() {
+    for value in [1_614_834_000, u64::MAX] {
+        let mut out = Vec::new();
+        Millis::new(value).write_varint(&mut out);
+        MillisDuration::from_millis(value).write_varint(&mut out);
+
+        let (millis, length) = Millis::read_varint(&out).unwrap();
+        assert_eq!(millis, Millis::new(value));
+        assert_eq!(
+            MillisDuration::read_varint(&out[length..]),
+            Some((MillisDuration::from_millis(value), length))
+        );
+    }
+}
+
+#[test_log::test]
+fn varint_truncated() {
+    let mut out = Vec::new();
+    Millis::new(1_000_000).write_varint(&mut out);
+    out.pop();
+
+    assert_eq!(Millis::read_varint(&out), None);
+    assert_eq!(MillisDuration::read_varint(&[]), None);
+    assert_eq!(Millis::read_varint(&[0xff; 11]), None);
+}
+
+#[test_log::test]
+fn wall_anchored_clock() {
+    let before = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64;
+    let clock = WallAnchoredClock::new();
+
+    let first = clock.now();
+    let delta = first - Millis::new(before);
+    assert!(delta.approx_eq(0.into(), 100.into()));
+
+    let mut previous = first;
+    for _ in 0..100 {
+        let now = clock.now();
+        assert!(now >= previous);
+        previous = now;
+    }
+}
+
+#[test_log::test]
+fn checked_sum() {
+    let durations = [16, 17, 17].map(MillisDuration::from_millis);
+    assert_eq!(
+        MillisDuration::checked_sum(durations.into_iter()),
+        Some(MillisDuration::from_millis(50))
+    );
+    assert_eq!(
+        MillisDuration::checked_sum(std::iter::empty()),
+        Some(MillisDuration::from_millis(0))
+    );
+}
+
+#[test_log::test]
+fn checked_sum_overflow() {
+    let durations = [u64::MAX - 10, 5, 6].map(MillisDuration::from_millis);
+    assert_eq!(MillisDuration::checked_sum(durations.into_iter()), None);
+}
+
+#[test_log::test]
+fn assert_millis_approx_passes() {
+    assert_millis_approx!(
+        Millis::new(1000),
+        Millis::new(1100),
+        MillisDuration::from_millis(100)
+    );
+    assert_millis_approx!(
+        MillisDuration::from_millis(950),
+        MillisDuration::from_millis(1000),
+        MillisDuration::from_millis(50),
+    );
+}
+
+#[test_log::test]
+#[should_panic(expected = "difference 101 ms exceeds tolerance 100 ms")]
+fn assert_millis_approx_fails() {
+    assert_millis_approx!(
+        Millis::new(1101),
+        Millis::new(1000),
+        MillisDuration::from_millis(100)
+    );
+}
+
+#[test_log::test]
+fn interpolating_clock_smooths_coarse_ticks() {
+    let clock = InterpolatingClock::with_fine_clock(
+        ManualClock::new(Millis::new(1000)),
+        ManualClock::new(Millis::new(0)),
+        MillisDuration::from_millis(10),
+    );
+    assert_eq!(clock.now(), Millis::new(1000));
+
+    clock.fine().advance(MillisDuration::from_millis(4));
+    assert_eq!(clock.now(), Millis::new(1004));
+
+    // The estimate never runs more than one coarse period ahead.
+    clock.fine().advance(MillisDuration::from_millis(20));
+    assert_eq!(clock.now(), Millis::new(1010));
+
+    clock.inner().advance(MillisDuration::from_millis(10));
+    clock.fine().advance(MillisDuration::from_millis(3));
+    assert_eq!(clock.now(), Millis::new(1010));
+    clock.fine().advance(MillisDuration::from_millis(3));
+    assert_eq!(clock.now(), Millis::new(1013));
+}
+
+#[test_log::test]
+fn interpolating_clock_is_monotonic() {
+    let clock = InterpolatingClock::with_fine_clock(
+        SequenceClock::new(&[100, 100, 108, 110, 120]),
+        SequenceClock::new(&[0, 9, 10, 12, 20]),
+        MillisDuration::from_millis(10),
+    );
+
+    let readings: Vec<Millis> = (0..5).map(|_| clock.now()).collect();
+    assert_eq!(
+        readings,
+        [100, 109, 109, 110, 120].map(Millis::new).to_vec()
+    );
+}
+
+#[test_log::test]
+fn display_duration_precision() {
+    let duration = MillisDuration::from_millis(1234);
+
+    assert_eq!(format!("{duration}"), "1234 ms");
+    assert_eq!(format!("{duration:.2}"), "1.23 s");
+    assert_eq!(format!("{duration:.3}"), "1.234 s");
+    assert_eq!(format!("{duration:.0}"), "1 s");
+}
+
+#[test_log::test]
+fn timeout_guard_fires_when_exceeded() {
+    let clock = ManualClock::new(Millis::new(0));
+    let mut exceeded = None;
+
+    {
+        let _guard = TimeoutGuard::new(&clock, MillisDuration::from_millis(100), |elapsed| {
+            exceeded = Some(elapsed);
+        });
+        clock.advance(MillisDuration::from_millis(150));
+    }
+
+    assert_eq!(exceeded, Some(MillisDuration::from_millis(150)));
+}
+
+#[test_log::test]
+fn timeout_guard_silent_within_threshold() {
+    let clock = ManualClock::new(Millis::new(0));
+    let mut fired = false;
+
+    {
+        let _guard = TimeoutGuard::new(&clock, MillisDuration::from_millis(100), |_| {
+            fired = true;
+        });
+        clock.advance(MillisDuration::from_millis(100));
+    }
+
+    assert!(!fired);
+}
+
+#[test_log::test]
+fn lower_bits_12() {
+    let window = MillisDuration::from_millis(1000);
+    let sent = Millis::new(0x0003_0ff0);
+    let lower = sent.to_lower_bits(12);
+    assert_eq!(lower, 0xff0);
+
+    // Same 12-bit period.
+    assert_eq!(
+        Millis::new(0x0003_0ffe).from_lower_bits(lower, 12, window),
+        Some(sent)
+    );
+    // Lower bits have wrapped.
+    assert_eq!(
+        Millis::new(0x0003_1020).from_lower_bits(lower, 12, window),
+        Some(sent)
+    );
+    // Too old for the window.
+    assert_eq!(
+        Millis::new(0x0003_1020).from_lower_bits(lower, 12, MillisDuration::from_millis(0x20)),
+        None
+    );
+}
+
+#[test_log::test]
+fn lower_bits_24() {
+    let window = MillisDuration::from_millis(5000);
+    let sent = Millis::new(0x0012_ffff_ff00);
+    let lower = sent.to_lower_bits(24);
+    assert_eq!(lower, 0xff_ff00);
+
+    let now = Millis::new(0x0013_0000_0100);
+    assert_eq!(now.from_lower_bits(lower, 24, window), Some(sent));
+    assert_eq!(now.from_lower_bits(0x100_0000, 24, window), None);
+    assert_eq!(Millis::new(5).from_lower_bits(0xfff, 12, window), None);
+    assert_eq!(sent.to_lower_bits(64), sent.absolute_milliseconds());
+    assert_eq!(sent.to_lower_bits(0), 0);
+}
+
+#[test_log::test]
+fn system_time_clock_survives_backward_jump() {
+    let system_time = AtomicU64::new(1_700_000_000_000);
+    let clock = SystemTimeMonotonicClock::with_time_source(|| {
+        std::time::UNIX_EPOCH + Duration::from_millis(system_time.load(Ordering::SeqCst))
+    });
+    assert_eq!(clock.now(), Millis::new(0));
+
+    system_time.fetch_add(500, Ordering::SeqCst);
+    assert_eq!(clock.now(), Millis::new(500));
+
+    system_time.fetch_sub(10_000, Ordering::SeqCst);
+    assert_eq!(clock.now(), Millis::new(500));
+
+    system_time.fetch_add(10_200, Ordering::SeqCst);
+    assert_eq!(clock.now(), Millis::new(700));
+}
+
+#[test_log::test]
+fn neg_duration() {
+    assert_eq!(
+        -MillisDuration::from_millis(500),
+        SignedMillisDuration::from_millis(-500)
+    );
+    assert_eq!(
+        -MillisDuration::from_millis(0),
+        SignedMillisDuration::from_millis(0)
+    );
+    assert_eq!(
+        -(-MillisDuration::from_millis(500)),
+        SignedMillisDuration::from_millis(500)
+    );
+}
+
+#[test_log::test]
+#[should_panic(expected = "overflow on neg millisduration")]
+fn neg_duration_overflow() {
+    let _ = -MillisDuration::from_millis(u64::MAX);
+}
+
+#[test_log::test]
+fn parse_validated() {
+    let min = Millis::new(100);
+    let max = Millis::new(5000);
+
+    assert_eq!(
+        Millis::parse_validated("2500", min, max),
+        Ok(Millis::new(2500))
+    );
+    assert_eq!(Millis::parse_validated("100", min, max), Ok(min));
+    assert_eq!(
+        Millis::parse_validated("99", min, max),
+        Err("value is below the minimum")
+    );
+    assert_eq!(
+        Millis::parse_validated("5001", min, max),
+        Err("value is above the maximum")
+    );
+    assert!(Millis::parse_validated("soon", min, max).is_err());
+    assert!(Millis::parse_validated("-5", min, max).is_err());
+}
+
+#[test_log::test]
+fn frame_clock() {
+    let clock = FrameClock::new(MillisDuration::from_millis(20));
+    assert_eq!(clock.now(), Millis::new(0));
+
+    for _ in 0..3 {
+        clock.advance_frame();
+    }
+
+    assert_eq!(clock.frame(), 3);
+    assert_eq!(clock.now(), Millis::new(60));
+}
+
+#[test_log::test]
+fn harmonic_mean() {
+    let frame_times = [16, 16, 16, 50].map(MillisDuration::from_millis);
+    let arithmetic = frame_times
+        .iter()
+        .fold(MillisDuration::from_millis(0), |total, duration| {
+            total + *duration
+        })
+        / frame_times.len() as u32;
+
+    assert_eq!(arithmetic, MillisDuration::from_millis(24));
+    assert_eq!(
+        MillisDuration::harmonic_mean(&frame_times),
+        Some(MillisDuration::from_millis(19))
+    );
+}
+
+#[test_log::test]
+fn harmonic_mean_rejects_empty_and_zero() {
+    assert_eq!(MillisDuration::harmonic_mean(&[]), None);
+    assert_eq!(
+        MillisDuration::harmonic_mean(&[10, 0].map(MillisDuration::from_millis)),
+        None
+    );
+}
+
+#[test_log::test]
+fn iso8601_duration() {
+    let cases = [
+        (0, "PT0S"),
+        (1500, "PT1.5S"),
+        (90_000, "PT1M30S"),
+        (3_600_000, "PT1H"),
+        (3_661_005, "PT1H1M1.005S"),
+        (120, "PT0.12S"),
+    ];
+    for (millis, expected) in cases {
+        assert_eq!(
+            MillisDuration::from_millis(millis).to_iso8601_duration(),
+            expected
+        );
+        assert_eq!(Millis::new(millis).to_iso8601_duration(), expected);
+    }
+}
+
+#[test_log::test]
+fn faulty_clock_failure_pattern() {
+    let clock = FaultyClock::new(ManualClock::new(Millis::new(42))).fail_every(3);
+
+    let pattern: Vec<bool> = (0..7).map(|_| clock.now_checked().is_some()).collect();
+    assert_eq!(pattern, [true, true, false, true, true, false, true]);
+}
+
+#[test_log::test]
+#[should_panic(expected = "FaultyClock injected a clock failure")]
+fn faulty_clock_now_panics() {
+    let clock = FaultyClock::new(ManualClock::new(Millis::new(42))).fail_every(2);
+
+    assert_eq!(clock.now(), Millis::new(42));
+    clock.now();
+}
+
+#[test_log::test]
+fn split_evenly() {
+    let parts = MillisDuration::from_millis(10).split_evenly(3);
+    assert_eq!(parts, [4, 3, 3].map(MillisDuration::from_millis).to_vec());
+    assert_eq!(
+        MillisDuration::checked_sum(parts.into_iter()),
+        Some(MillisDuration::from_millis(10))
+    );
+
+    assert_eq!(
+        MillisDuration::from_millis(2).split_evenly(4),
+        [1, 1, 0, 0].map(MillisDuration::from_millis).to_vec()
+    );
+}
+
+#[test_log::test]
+#[should_panic(expected = "zero parts")]
+fn split_evenly_zero_parts() {
+    MillisDuration::from_millis(10).split_evenly(0);
+}
+
+#[test_log::test]
+fn clock_mapping_interpolates_and_extrapolates() {
+    let mapping = ClockMapping::from_anchors(
+        Millis::new(1000),
+        Millis::new(10_000),
+        Millis::new(3000),
+        Millis::new(11_000),
+    )
+    .unwrap();
+
+    assert_eq!(mapping.scale(), 0.5);
+    assert_eq!(mapping.map(Millis::new(2000)), Millis::new(10_500));
+    assert_eq!(mapping.map(Millis::new(5000)), Millis::new(12_000));
+    assert_eq!(mapping.map(Millis::new(0)), Millis::new(9_500));
+}
+
+#[test_log::test]
+fn clock_mapping_rejects_equal_anchors() {
+    assert!(ClockMapping::from_anchors(
+        Millis::new(1000),
+        Millis::new(10_000),
+        Millis::new(1000),
+        Millis::new(11_000),
+    )
+    .is_err());
+}
+
+#[test_log::test]
+fn to_number_string() {
+    assert_eq!(MillisDuration::from_millis(1500).to_number_string(), "1500");
+    assert_eq!(Millis::new(0).to_number_string(), "0");
+    assert_eq!(format!("{}", Millis::new(42)), "42 ms");
+}
+
+#[test_log::test]
+fn recording_clock_replays() {
+    let recording = RecordingClock::new(ManualClock::new(Millis::new(100)));
+    let mut expected = Vec::new();
+    for step in [0, 16, 17, 250] {
+        recording.inner().advance(MillisDuration::from_millis(step));
+        expected.push(recording.now());
+    }
+
+    let playback = PlaybackClock::new(recording.into_recording());
+    assert_eq!(playback.remaining(), 4);
+    let replayed: Vec<Millis> = (0..4).map(|_| playback.now()).collect();
+
+    assert_eq!(replayed, expected);
+    assert_eq!(playback.now_checked(), None);
+}
+
+#[cfg(feature = "serde")]
+#[test_log::test]
+fn recording_clock_persists_with_serde() {
+    let recording = RecordingClock::new(SequenceClock::new(&[5, 21, 38]));
+    for _ in 0..3 {
+        recording.now();
+    }
+
+    let json = serde_json::to_string(&recording.recording()).unwrap();
+    assert_eq!(json, "[5,21,38]");
+
+    let playback = PlaybackClock::new(serde_json::from_str(&json).unwrap());
+    assert_eq!(playback.now(), Millis::new(5));
+    assert_eq!(playback.now(), Millis::new(21));
+    assert_eq!(playback.now(), Millis::new(38));
+}
+
+#[test_log::test]
+fn earliest_and_latest() {
+    let events = [250, 40, 990, 41].map(Millis::new);
+
+    assert_eq!(Millis::earliest(&events), Some(Millis::new(40)));
+    assert_eq!(Millis::latest(&events), Some(Millis::new(990)));
+    assert_eq!(Millis::earliest(&[]), None);
+    assert_eq!(Millis::latest(&[]), None);
+}
+
+#[test_log::test]
+fn div_duration_by_float() {
+    assert_eq!(
+        MillisDuration::from_millis(1000) / 4.0,
+        MillisDuration::from_millis(250)
+    );
+    assert_eq!(
+        MillisDuration::from_millis(1000) / 3.0,
+        MillisDuration::from_millis(333)
+    );
+    assert_eq!(
+        MillisDuration::from_millis(1000) / 0.5,
+        MillisDuration::from_millis(2000)
+    );
+}
+
+#[test_log::test]
+#[should_panic(expected = "divide by zero")]
+fn div_duration_by_zero_float() {
+    let _ = MillisDuration::from_millis(1000) / 0.0;
+}
+
+#[test_log::test]
+fn phase_accumulator_wraps() {
+    let mut oscillator = PhaseAccumulator::new(1.0);
+
+    assert_eq!(oscillator.advance(MillisDuration::from_millis(500)), 0.5);
+    assert_eq!(oscillator.advance(MillisDuration::from_millis(750)), 0.25);
+    assert_eq!(oscillator.advance(MillisDuration::from_millis(2000)), 0.25);
+}
+
+#[test_log::test]
+fn millis_from_secs_f64() {
+    assert_eq!(
+        Millis::from_secs_f64(1_614_834.5),
+        Ok(Millis::new(1_614_834_500))
+    );
+    assert_eq!(Millis::from_secs_f64(0.0004), Ok(Millis::new(0)));
+    assert!(Millis::from_secs_f64(-0.5).is_err());
+    assert!(Millis::from_secs_f64(f64::NAN).is_err());
+    assert!(Millis::from_secs_f64(f64::INFINITY).is_err());
+    assert!(Millis::from_secs_f64(1e20).is_err());
+}
+
+#[test_log::test]
+fn faster_of() {
+    let naive = ("naive", MillisDuration::from_millis(120));
+    let cached = ("cached", MillisDuration::from_millis(15));
+
+    assert_eq!(MillisDuration::faster_of(naive, cached), "cached");
+    assert_eq!(MillisDuration::faster_of(cached, naive), "cached");
+    assert_eq!(
+        MillisDuration::faster_of(("first", 10.into()), ("second", 10.into())),
+        "first"
+    );
+}
+
+#[test_log::test]
+fn with_default_clock_reads_now() {
+    let (first, second) = with_default_clock(|clock| (clock.now(), clock.now()));
+
+    assert!(second >= first);
+}
+
+#[cfg(feature = "rkyv")]
+#[test_log::test]
+fn rkyv_zero_copy_access() {
+    use monotonic_time_rs::{ArchivedMillis, ArchivedMillisDuration};
+
+    let timestamp = Millis::new(1_614_834_000);
+    let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&timestamp).unwrap();
+    let archived = rkyv::access::<ArchivedMillis, rkyv::rancor::Error>(&bytes).unwrap();
+    assert_eq!(archived, &timestamp);
+    assert_eq!(
+        rkyv::deserialize::<Millis, rkyv::rancor::Error>(archived).unwrap(),
+        timestamp
+    );
+
+    let duration = MillisDuration::from_millis(4000);
+    let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&duration).unwrap();
+    let archived = rkyv::access::<ArchivedMillisDuration, rkyv::rancor::Error>(&bytes).unwrap();
+    assert_eq!(archived, &duration);
+}
+
+#[test_log::test]
+#[allow(deprecated)]
+fn as_secs_f64_is_exact() {
+    let duration = MillisDuration::from_millis(86_400_001);
+
+    assert_eq!(duration.as_secs_f64(), 86_400.001);
+    assert_ne!(duration.as_secs() as f64, 86_400.001);
+}
+
+#[test_log::test]
+fn clock_delta_between_manual_clocks() {
+    let a = ManualClock::new(Millis::new(10_000));
+    let b = ManualClock::new(Millis::new(2_500));
+
+    assert_eq!(
+        clock_delta(&a, &b),
+        SignedMillisDuration::from_millis(7_500)
+    );
+    assert_eq!(
+        clock_delta(&b, &a),
+        SignedMillisDuration::from_millis(-7_500)
+    );
+
+    b.advance(MillisDuration::from_millis(7_500));
+    assert_eq!(clock_delta(&a, &b), SignedMillisDuration::from_millis(0));
+}
+
+#[test_log::test]
+fn lower_with_wrap_count_round_trip() {
+    for millis in [
+        0x0000_fffe,
+        0x0000_ffff,
+        0x0001_0000,
+        0x0001_0001,
+        0xffff_ffff,
+    ] {
+        let timestamp = Millis::new(millis);
+        let (lower, wraps) = timestamp.lower_with_wrap_count();
+        assert_eq!(Millis::from_lower_with_wrap_count(lower, wraps), timestamp);
+    }
+
+    let before = Millis::new(0x0004_ffff).lower_with_wrap_count();
+    let after = Millis::new(0x0005_0000).lower_with_wrap_count();
+    assert!(after.0 < before.0);
+    assert!((after.1, after.0) > (before.1, before.0));
+}
+
+#[test_log::test]
+fn schedule_arithmetic_sequence() {
+    let step = MillisDuration::from_millis(250);
+    let limit = MillisDuration::from_millis(1000);
+
+    let schedule: Vec<MillisDuration> = MillisDuration::schedule(step, |previous| {
+        let next = previous + step;
+        (next <= limit).then_some(next)
+    })
+    .collect();
+
+    assert_eq!(
+        schedule,
+        [250, 500, 750, 1000]
+            .map(MillisDuration::from_millis)
+            .to_vec()
+    );
+}
+
+#[test_log::test]
+fn first_divergence() {
+    let recorded = [100, 116, 133, 150].map(Millis::new);
+
+    assert_eq!(Millis::first_divergence(&recorded, &recorded), None);
+    assert_eq!(
+        Millis::first_divergence(&recorded, &[100, 116, 134, 150].map(Millis::new)),
+        Some(2)
+    );
+    assert_eq!(Millis::first_divergence(&recorded, &recorded[..3]), Some(3));
+    assert_eq!(Millis::first_divergence(&[], &recorded), Some(0));
+}
+
+#[cfg(feature = "serde")]
+#[test_log::test]
+fn serde_millis_is_transparent() {
+    let timestamp = Millis::new(1000);
+
+    assert_eq!(serde_json::to_string(&timestamp).unwrap(), "1000");
+    assert_eq!(serde_json::from_str::<Millis>("1000").unwrap(), timestamp);
+
+    let max = Millis::new(u64::MAX);
+    let json = serde_json::to_string(&max).unwrap();
+    assert_eq!(serde_json::from_str::<Millis>(&json).unwrap(), max);
+}
+
+#[cfg(feature = "serde")]
+#[test_log::test]
+fn serde_millis_bincode_round_trip() {
+    for timestamp in [0, 1_614_834_000, u64::MAX].map(Millis::new) {
+        let bytes = bincode::serialize(&timestamp).unwrap();
+        assert_eq!(bytes.len(), 8);
+        assert_eq!(bincode::deserialize::<Millis>(&bytes).unwrap(), timestamp);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test_log::test]
+fn serde_millis_rejects_negative_and_float() {
+    let negative = serde_json::from_str::<Millis>("-5").unwrap_err();
+    assert!(negative.to_string().contains("expected u64"), "{negative}");
+
+    let float = serde_json::from_str::<Millis>("1.5").unwrap_err();
+    assert!(float.to_string().contains("expected u64"), "{float}");
+}
+
+#[test_log::test]
+fn counting_clock() {
+    let clock = CountingClock::new(ManualClock::new(Millis::new(7)));
+
+    for _ in 0..5 {
+        assert_eq!(clock.now(), Millis::new(7));
+    }
+
+    assert_eq!(clock.call_count(), 5);
+    clock.reset_count();
+    assert_eq!(clock.call_count(), 0);
+}
+
+#[cfg(all(feature = "serde", not(feature = "serde_human")))]
+#[test_log::test]
+fn serde_duration_json_is_plain_millis() {
+    for (duration, json) in [
+        (MillisDuration::from_millis(4000), "4000"),
+        (MillisDuration::default(), "0"),
+    ] {
+        assert_eq!(serde_json::to_string(&duration).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<MillisDuration>(json).unwrap(),
+            duration
+        );
+    }
+    assert!(serde_json::from_str::<MillisDuration>("-1").is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test_log::test]
+fn serde_duration_bincode_stays_compact() {
+    for duration in [
+        MillisDuration::default(),
+        MillisDuration::from_millis(4000),
+        MillisDuration::from_millis(1234),
+    ] {
+        let bytes = bincode::serialize(&duration).unwrap();
+        assert_eq!(bytes.len(), 8);
+        assert_eq!(
+            bincode::deserialize::<MillisDuration>(&bytes).unwrap(),
+            duration
+        );
+    }
+}
+
+#[cfg(feature = "serde_human")]
+#[test_log::test]
+fn serde_duration_json_is_human() {
+    for (millis, json) in [
+        (0, "\"0ms\""),
+        (1500, "\"1500ms\""),
+        (4000, "\"4s\""),
+        (120_000, "\"2m\""),
+        (7_200_000, "\"2h\""),
+    ] {
+        let duration = MillisDuration::from_millis(millis);
+        assert_eq!(serde_json::to_string(&duration).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<MillisDuration>(json).unwrap(),
+            duration
+        );
+    }
+    assert_eq!(
+        serde_json::from_str::<MillisDuration>("4000").unwrap(),
+        MillisDuration::from_millis(4000)
+    );
+    assert!(serde_json::from_str::<MillisDuration>("\"4 parsecs\"").is_err());
+}
+
+#[test_log::test]
+fn duration_period_constants() {
+    assert_eq!(MillisDuration::SECOND.as_millis(), 1000);
+    assert_eq!(MillisDuration::MINUTE.as_millis(), 60_000);
+    assert_eq!(MillisDuration::HOUR, MillisDuration::MINUTE * 60);
+    assert_eq!(MillisDuration::DAY.as_millis(), 86_400_000);
+    assert_eq!(MillisDuration::FRAME_60HZ.as_millis(), 16);
+}
+
+#[test_log::test]
+fn checked_add_millis() {
+    assert_eq!(
+        Millis::new(1000).checked_add(MillisDuration::SECOND),
+        Some(Millis::new(2000))
+    );
+    assert_eq!(
+        Millis::new(u64::MAX).checked_add(MillisDuration::from_millis(0)),
+        Some(Millis::new(u64::MAX))
+    );
+    assert_eq!(
+        Millis::new(u64::MAX).checked_add(MillisDuration::from_millis(1)),
+        None
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test_log::test]
+fn serde_duration_accepts_float_millis() {
+    for (json, millis) in [
+        ("1500", 1500),
+        ("1500.0", 1500),
+        ("1500.4", 1500),
+        ("1500.5", 1501),
+    ] {
+        assert_eq!(
+            serde_json::from_str::<MillisDuration>(json).unwrap(),
+            MillisDuration::from_millis(millis)
+        );
+    }
+    assert!(serde_json::from_str::<MillisDuration>("-1.5").is_err());
+    assert!(serde_json::from_str::<MillisDuration>("1e30").is_err());
+    assert!(serde_json::from_str::<MillisDuration>("18446744073709551616.0").is_err());
+}
+
+#[test_log::test]
+fn saturating_add_and_sub_millis() {
+    let duration = MillisDuration::from_millis(100);
+    assert_eq!(
+        Millis::new(1000).saturating_add(duration),
+        Millis::new(1100)
+    );
+    assert_eq!(Millis::new(1000).saturating_sub(duration), Millis::new(900));
+    assert_eq!(
+        Millis::new(u64::MAX - 10).saturating_add(duration),
+        Millis::new(u64::MAX)
+    );
+    assert_eq!(Millis::new(10).saturating_sub(duration), Millis::new(0));
+}
+
+#[test_log::test]
+fn clamped_step_clock_smooths_big_jump() {
+    let clock = ClampedStepClock::new(
+        SequenceClock::new(&[1000, 1016, 61_016, 61_032, 61_048]),
+        MillisDuration::from_millis(100),
+    );
+    assert_eq!(clock.now(), Millis::new(1000));
+    assert_eq!(clock.now(), Millis::new(1016));
+    assert_eq!(clock.now(), Millis::new(1116));
+    assert_eq!(clock.now(), Millis::new(1216));
+    assert_eq!(clock.now(), Millis::new(1316));
+}
+
+#[test_log::test]
+fn clamped_step_clock_passes_small_steps_and_never_goes_back() {
+    let clock = ClampedStepClock::new(
+        SequenceClock::new(&[500, 550, 540, 600]),
+        MillisDuration::from_millis(100),
+    );
+    assert_eq!(clock.now(), Millis::new(500));
+    assert_eq!(clock.now(), Millis::new(550));
+    assert_eq!(clock.now(), Millis::new(550));
+    assert_eq!(clock.now(), Millis::new(600));
+}
+
+#[test_log::test]
+fn manual_clock_advance_and_set() {
+    let clock = ManualClock::new(Millis::new(1000));
+    let deadline = clock.now() + MillisDuration::from_millis(500);
+    clock.advance(MillisDuration::from_millis(499));
+    assert!(clock.now() < deadline);
+    clock.advance(MillisDuration::from_millis(1));
+    assert_eq!(clock.now(), deadline);
+    clock.set(Millis::new(10));
+    assert_eq!(clock.now(), Millis::new(10));
+}
+
+#[test_log::test]
+fn secs_and_millis_split() {
+    assert_eq!(
+        MillisDuration::from_millis(1500).secs_and_millis(),
+        (1, 500)
+    );
+    assert_eq!(MillisDuration::from_millis(999).secs_and_millis(), (0, 999));
+    assert_eq!(MillisDuration::from_millis(2000).secs_and_millis(), (2, 0));
+}
+
+#[test_log::test]
+fn mul_u32_does_not_truncate() {
+    let duration = MillisDuration::from_millis(5_000_000_000);
+    assert_eq!((duration * 2).as_millis(), 10_000_000_000);
+    assert_eq!((2 * duration).as_millis(), 10_000_000_000);
+    let mut assigned = duration;
+    assigned *= 3;
+    assert_eq!(assigned.as_millis(), 15_000_000_000);
+}
+
+#[test_log::test]
+#[should_panic(expected = "overflow on mul millisduration")]
+fn mul_u32_overflow() {
+    let _ = MillisDuration::from_millis(u64::MAX) * 2;
+}
+
+#[test_log::test]
+#[allow(clippy::op_ref)]
+fn arithmetic_on_references() {
+    let start = Millis::new(1000);
+    let end = Millis::new(1500);
+    let duration = MillisDuration::from_millis(500);
+
+    assert_eq!(&start + duration, end);
+    assert_eq!(start + &duration, end);
+    assert_eq!(&start + &duration, end);
+    assert_eq!(&end - &duration, start);
+    assert_eq!(&end - &start, duration);
+    assert_eq!(end - &start, duration);
+    assert_eq!(&end - start, duration);
+    assert_eq!(&duration + &duration, MillisDuration::SECOND);
+    assert_eq!(&duration - &duration, MillisDuration::default());
+
+    fn total<'a, T>(items: &'a [T], zero: T) -> T
+    where
+        T: Copy + 'a,
+        &'a T: std::ops::Add<&'a T, Output = T>,
+        T: std::ops::Add<&'a T, Output = T>,
+    {
+        items.iter().fold(zero, |acc, item| acc + item)
+    }
+    assert_eq!(
+        total(&[duration, duration], MillisDuration::default()),
+        MillisDuration::SECOND
+    );
+}
+
+#[test_log::test]
+fn from_secs_f64_keeps_precision() {
+    let seconds = 86_400.5;
+    let precise = MillisDuration::from_secs_f64(seconds).unwrap();
+    let lossy = MillisDuration::from_secs(seconds as f32).unwrap();
+    assert_eq!(precise.as_millis(), 86_400_500);
+    assert_ne!(lossy, precise);
+    assert_eq!(
+        MillisDuration::from_secs_f64(0.0015),
+        Ok(MillisDuration::from_millis(2))
+    );
+}
+
+#[test_log::test]
+fn from_secs_f64_rejects_invalid() {
+    assert_eq!(
+        MillisDuration::from_secs_f64(-1.0),
+        Err("must be a positive value")
+    );
+    assert_eq!(
+        MillisDuration::from_secs_f64(f64::NAN),
+        Err("must be a number")
+    );
+    assert_eq!(
+        MillisDuration::from_secs_f64(f64::INFINITY),
+        Err("must be a number")
+    );
+    assert!(MillisDuration::from_secs_f64(f64::NEG_INFINITY).is_err());
+}
+
+#[test_log::test]
+fn millis_duration_from_std_duration() {
+    use std::time::Duration;
+
+    assert_eq!(
+        MillisDuration::from(Duration::from_millis(1500)),
+        MillisDuration::from_millis(1500)
+    );
+    assert_eq!(
+        MillisDuration::from(Duration::from_micros(999)),
+        MillisDuration::from_millis(0)
+    );
+    assert_eq!(
+        MillisDuration::try_from_duration(Duration::from_secs(3)),
+        Ok(MillisDuration::from_millis(3000))
+    );
+}
+
+#[test_log::test]
+fn millis_duration_from_std_duration_overflow() {
+    use std::time::Duration;
+
+    let huge = Duration::from_secs(u64::MAX);
+    assert_eq!(
+        MillisDuration::from(huge),
+        MillisDuration::from_millis(u64::MAX)
+    );
+    assert_eq!(
+        MillisDuration::try_from_duration(huge),
+        Err("duration is too large")
+    );
+}
+
+#[test_log::test]
+fn dual_clock_smooths_noisy_readings() {
+    let clock = DualClock::new(SequenceClock::new(&[1000, 1100, 1000, 1100]), 0.5);
+    assert_eq!(clock.now_smoothed(), Millis::new(1000));
+    assert_eq!(clock.now_smoothed(), Millis::new(1050));
+    assert_eq!(clock.now_smoothed(), Millis::new(1050));
+    assert_eq!(clock.now(), Millis::new(1063));
+}
+
+#[test_log::test]
+fn dual_clock_smoothed_lags_raw_after_jump() {
+    let clock = DualClock::new(SequenceClock::new(&[0, 1000, 1000, 1000]), 0.25);
+    assert_eq!(clock.now_raw(), Millis::new(0));
+    assert_eq!(clock.now_raw(), Millis::new(1000));
+    assert_eq!(clock.now_smoothed(), Millis::new(438));
+    assert_eq!(clock.now_smoothed(), Millis::new(578));
+}
+
+#[test_log::test]
+fn from_duration_lossy_detects_sub_millis() {
+    use std::time::Duration;
+
+    assert_eq!(
+        MillisDuration::from_duration_lossy(Duration::from_micros(1500)),
+        (MillisDuration::from_millis(1), true)
+    );
+    assert_eq!(
+        MillisDuration::from_duration_lossy(Duration::from_millis(2000)),
+        (MillisDuration::from_millis(2000), false)
+    );
+    assert_eq!(
+        MillisDuration::from_duration_lossy(Duration::from_nanos(1)),
+        (MillisDuration::from_millis(0), true)
+    );
+}
+
+#[test_log::test]
+fn std_duration_round_trip() {
+    use std::time::Duration;
+
+    for duration in [
+        Duration::ZERO,
+        Duration::from_millis(16),
+        Duration::from_secs(90),
+        Duration::from_millis(u64::MAX),
+    ] {
+        let millis = MillisDuration::from(duration);
+        assert_eq!(Duration::from(millis), duration);
+    }
+}
+
+#[test_log::test]
+fn with_jitter_is_deterministic_for_fixed_rng() {
+    let scheduled = Millis::new(10_000);
+    let max_jitter = MillisDuration::from_millis(1000);
+    let mut fractions = [0.0, 0.5, 0.999].into_iter();
+    let mut rng = || fractions.next().unwrap();
+
+    assert_eq!(
+        scheduled.with_jitter(max_jitter, &mut rng),
+        Millis::new(10_000)
+    );
+    assert_eq!(
+        scheduled.with_jitter(max_jitter, &mut rng),
+        Millis::new(10_500)
+    );
+    assert_eq!(
+        scheduled.with_jitter(max_jitter, &mut rng),
+        Millis::new(10_999)
+    );
+    assert_eq!(
+        scheduled.with_jitter(max_jitter, &mut || 7.0),
+        Millis::new(11_000)
+    );
+}
+
+#[test_log::test]
+fn abs_diff_ignores_order() {
+    let earlier = Millis::new(1000);
+    let later = Millis::new(4500);
+    assert_eq!(earlier.abs_diff(later), MillisDuration::from_millis(3500));
+    assert_eq!(later.abs_diff(earlier), MillisDuration::from_millis(3500));
+    assert_eq!(later.abs_diff(later), MillisDuration::from_millis(0));
+    assert_eq!(
+        Millis::new(0).abs_diff(Millis::new(u64::MAX)),
+        MillisDuration::from_millis(u64::MAX)
+    );
+}
+
+#[test_log::test]
+fn drift_estimator_constant_offset() {
+    let mut estimator = DriftEstimator::new(0.25);
+    assert!(!estimator.has_observations());
+    assert_eq!(
+        estimator.estimated_offset(),
+        SignedMillisDuration::from_millis(0)
+    );
+    for local in [1000, 2000, 3000] {
+        estimator.observe(Millis::new(local), Millis::new(local - 30));
+    }
+    assert_eq!(
+        estimator.estimated_offset(),
+        SignedMillisDuration::from_millis(-30)
+    );
+}
+
+#[test_log::test]
+fn drift_estimator_follows_changing_offset() {
+    let mut estimator = DriftEstimator::new(0.5);
+    estimator.observe(Millis::new(1000), Millis::new(1000));
+    estimator.observe(Millis::new(2000), Millis::new(2100));
+    assert_eq!(
+        estimator.estimated_offset(),
+        SignedMillisDuration::from_millis(50)
+    );
+    estimator.observe(Millis::new(3000), Millis::new(3100));
+    assert_eq!(
+        estimator.estimated_offset(),
+        SignedMillisDuration::from_millis(75)
+    );
+}
+
+#[test_log::test]
+fn format_hms_timecode() {
+    assert_eq!(
+        MillisDuration::from_millis(3_661_500).format_hms(),
+        "01:01:01.500"
+    );
+    assert_eq!(MillisDuration::from_millis(0).format_hms(), "00:00:00.000");
+    assert_eq!(
+        MillisDuration::from_millis(360_000_007).format_hms(),
+        "100:00:00.007"
+    );
+    assert_eq!(Millis::new(59_999).format_hms(), "00:00:59.999");
+}
+
+#[cfg(feature = "defmt")]
+#[test_log::test]
+fn defmt_format_is_implemented() {
+    fn assert_format<T: defmt::Format>(_: &T) {}
+
+    assert_format(&Millis::new(1000));
+    assert_format(&MillisDuration::from_millis(16));
+}
+
+#[test_log::test]
+fn parse_hms_round_trip() {
+    for millis in [0, 999, 59_000, 3_661_500, 360_000_007] {
+        let duration = MillisDuration::from_millis(millis);
+        assert_eq!(
+            MillisDuration::parse_hms(&duration.format_hms()),
+            Ok(duration)
+        );
+    }
+    assert_eq!(
+        MillisDuration::parse_hms("02:30"),
+        Ok(MillisDuration::from_millis(150_000))
+    );
+    assert_eq!(
+        MillisDuration::parse_hms("00:01.250"),
+        Ok(MillisDuration::from_millis(1250))
+    );
+    assert_eq!(
+        MillisDuration::parse_hms("10:00:00"),
+        Ok(MillisDuration::HOUR * 10)
+    );
+}
+
+#[test_log::test]
+fn parse_hms_rejects_malformed() {
+    for input in [
+        "",
+        "12",
+        "1:2:3",
+        "00:60",
+        "00:00:5",
+        "00:00:00.5",
+        "00:00:00.5000",
+        "aa:bb",
+        "+1:00:00",
+        "00:00:00:00",
+        "00:00.",
+        " 00:00",
+        "99999999999999999999:00:00",
+    ] {
+        assert!(MillisDuration::parse_hms(input).is_err(), "{input:?}");
+    }
+}
+
+#[test_log::test]
+fn human_duration_display() {
+    let human = |millis| MillisDuration::from_millis(millis).human().to_string();
+    assert_eq!(human(500), "500ms");
+    assert_eq!(human(3_661_000), "1h 1m 1s");
+    assert_eq!(human(7_200_250), "2h 250ms");
+    assert_eq!(human(0), "0ms");
+    assert_eq!(MillisDuration::from_millis(4000).to_string(), "4000 ms");
+}
+
+#[test_log::test]
+fn active_time_clock_excludes_suspend() {
+    let clock = ActiveTimeClock::new(
+        ManualClock::new(Millis::new(5000)),
+        MillisDuration::from_millis(1000),
+    );
+    assert_eq!(clock.now(), Millis::new(5000));
+
+    clock.inner().advance(MillisDuration::from_millis(500));
+    clock.heartbeat();
+    assert_eq!(clock.now(), Millis::new(5500));
+
+    clock.inner().advance(MillisDuration::HOUR);
+    assert_eq!(clock.now(), Millis::new(6500));
+    clock.heartbeat();
+    assert_eq!(clock.now(), Millis::new(6500));
+
+    clock.inner().advance(MillisDuration::from_millis(250));
+    assert_eq!(clock.now(), Millis::new(6750));
+}
+
+#[test_log::test]
+fn duration_from_str_units() {
+    for (input, millis) in [
+        ("1500ms", 1500),
+        ("30s", 30_000),
+        ("2m", 120_000),
+        ("1h", 3_600_000),
+        ("250", 250),
+        (" 10 s ", 10_000),
+    ] {
+        assert_eq!(input.parse(), Ok(MillisDuration::from_millis(millis)));
+    }
+}
+
+#[test_log::test]
+fn duration_from_str_errors() {
+    assert_eq!(
+        "abc".parse::<MillisDuration>(),
+        Err("expected an integer duration")
+    );
+    assert_eq!(
+        "5 days".parse::<MillisDuration>(),
+        Err("unknown duration unit, expected ms, s, m or h")
+    );
+    assert_eq!(
+        "18446744073709551615s".parse::<MillisDuration>(),
+        Err("duration overflows u64 milliseconds")
+    );
+    assert_eq!(
+        "99999999999999999999".parse::<MillisDuration>(),
+        Err("duration overflows u64 milliseconds")
+    );
+    assert!("".parse::<MillisDuration>().is_err());
+    assert!("-5s".parse::<MillisDuration>().is_err());
+}
+
+#[test_log::test]
+fn compare_millis_duration_with_std_duration() {
+    use std::time::Duration;
+
+    let duration = MillisDuration::from_millis(1500);
+    assert_eq!(duration, Duration::from_millis(1500));
+    assert_ne!(duration, Duration::from_secs(2));
+    assert!(duration < Duration::from_secs(2));
+    assert!(duration > Duration::from_secs(1));
+    assert_eq!(duration, Duration::from_micros(1_500_999));
+    assert!(MillisDuration::from_millis(u64::MAX) < Duration::MAX);
+}
+
+#[test_log::test]
+fn offset_clock_shifts_readings() {
+    let clock = OffsetClock::new(
+        ManualClock::new(Millis::new(0)),
+        MillisDuration::from_millis(1000),
+    );
+    assert_eq!(clock.now(), Millis::new(1000));
+    clock.inner().advance(MillisDuration::from_millis(250));
+    assert_eq!(clock.now(), Millis::new(1250));
+
+    let behind = OffsetClock::behind(
+        ManualClock::new(Millis::new(500)),
+        MillisDuration::from_millis(1000),
+    );
+    assert_eq!(behind.now(), Millis::new(0));
+    behind.inner().advance(MillisDuration::from_millis(750));
+    assert_eq!(behind.now(), Millis::new(250));
+}
+
+#[cfg(feature = "tokio")]
+#[test_log::test]
+fn watch_clock_follows_sender() {
+    use monotonic_time_rs::WatchClock;
+
+    let (sender, receiver) = tokio::sync::watch::channel(Millis::new(1000));
+    let clock = WatchClock::new(receiver);
+    assert_eq!(clock.now(), Millis::new(1000));
+    sender.send(Millis::new(1016)).unwrap();
+    assert_eq!(clock.now(), Millis::new(1016));
+    sender.send(Millis::new(1032)).unwrap();
+    drop(sender);
+    assert_eq!(clock.now(), Millis::new(1032));
+}
+
+#[test_log::test]
+fn scaled_clock_dilates_time() {
+    let fast = ScaledClock::new(ManualClock::new(Millis::new(5000)), 2.0);
+    fast.inner().advance(MillisDuration::from_millis(1000));
+    assert_eq!(fast.now(), Millis::new(7000));
+
+    let slow = ScaledClock::new(ManualClock::new(Millis::new(5000)), 0.25);
+    slow.inner().advance(MillisDuration::from_millis(1000));
+    assert_eq!(slow.now(), Millis::new(5250));
+    slow.inner().advance(MillisDuration::from_millis(3));
+    assert_eq!(slow.now(), Millis::new(5250));
+
+    let frozen = ScaledClock::new(ManualClock::new(Millis::new(5000)), 0.0);
+    frozen.inner().advance(MillisDuration::HOUR);
+    assert_eq!(frozen.now(), Millis::new(5000));
+}
+
+#[test_log::test]
+#[should_panic(expected = "ScaledClock scale must be finite and non-negative")]
+fn scaled_clock_rejects_negative_scale() {
+    let _ = ScaledClock::new(ManualClock::new(Millis::new(0)), -1.0);
+}
+
+#[test_log::test]
+fn lower48_round_trip() {
+    let now = Millis::new(1_700_000_000_000);
+    for sent in [now, Millis::new(0), Millis::new(1_699_999_999_000)] {
+        assert_eq!(now.from_lower48(sent.to_lower48()), Some(sent));
+    }
+}
+
+#[test_log::test]
+fn lower48_wraps_around_boundary() {
+    let boundary = 1u64 << 48;
+    let sent = Millis::new(boundary - 10);
+    let now = Millis::new(boundary + 5);
+    assert_eq!(sent.to_lower48(), [0xff, 0xff, 0xff, 0xff, 0xff, 0xf6]);
+    assert_eq!(now.from_lower48(sent.to_lower48()), Some(sent));
+
+    let ahead = Millis::new(100);
+    assert_eq!(Millis::new(50).from_lower48(ahead.to_lower48()), None);
+}
+
+#[test_log::test]
+fn div_duration_floor_and_ceil() {
+    let frame = MillisDuration::from_millis(16);
+    assert_eq!(MillisDuration::from_millis(50).div_duration_ceil(frame), 4);
+    assert_eq!(MillisDuration::from_millis(50).div_duration_floor(frame), 3);
+    assert_eq!(MillisDuration::from_millis(48).div_duration_ceil(frame), 3);
+    assert_eq!(MillisDuration::from_millis(48).div_duration_floor(frame), 3);
+    assert_eq!(MillisDuration::from_millis(0).div_duration_ceil(frame), 0);
+}
+
+#[test_log::test]
+#[should_panic(expected = "divide by zero error millisduration")]
+fn div_duration_ceil_by_zero() {
+    let _ = MillisDuration::SECOND.div_duration_ceil(MillisDuration::from_millis(0));
+}
+
+#[test_log::test]
+fn lower32_wraps_around_after_49_days() {
+    let wrap = 1u64 << 32;
+    let sent = Millis::new(wrap - 25_000);
+    let now = Millis::new(wrap + 30_000);
+    assert_eq!(sent.to_lower32(), u32::MAX - 24_999);
+    assert_eq!(now.from_lower32(sent.to_lower32()), Some(sent));
+    assert_eq!(
+        Millis::new(wrap + 40_000).from_lower32(sent.to_lower32()),
+        None
+    );
+}
+
+#[test_log::test]
+fn lower32_custom_threshold() {
+    let sent = Millis::new(5_000_000);
+    let now = sent + MillisDuration::from_millis(120_000);
+    assert_eq!(now.from_lower32(sent.to_lower32()), None);
+    assert_eq!(
+        now.from_lower32_within(sent.to_lower32(), MillisDuration::from_millis(120_000)),
+        Some(sent)
+    );
+}
+
+#[test_log::test]
+fn time_id_generator_counts_within_millisecond() {
+    let clock = ManualClock::new(Millis::new(1000));
+    let mut generator = TimeIdGenerator::new();
+    let ids: Vec<u64> = (0..3).map(|_| generator.next(&clock)).collect();
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(TimeIdGenerator::split(ids[2]), (Millis::new(1000), 2));
+
+    clock.advance(MillisDuration::from_millis(1));
+    let next = generator.next(&clock);
+    assert!(next > ids[2]);
+    assert_eq!(TimeIdGenerator::split(next), (Millis::new(1001), 0));
+}
+
+#[test_log::test]
+fn time_id_generator_spins_on_counter_overflow() {
+    let per_millisecond = 1usize << TimeIdGenerator::COUNTER_BITS;
+    let mut readings = vec![1000; per_millisecond + 5];
+    readings.push(1001);
+    let clock = SequenceClock::new(&readings);
+    let mut generator = TimeIdGenerator::new();
+
+    let mut previous = generator.next(&clock);
+    for _ in 1..per_millisecond {
+        let id = generator.next(&clock);
+        assert!(id > previous);
+        previous = id;
+    }
+    assert_eq!(
+        TimeIdGenerator::split(previous),
+        (Millis::new(1000), per_millisecond as u64 - 1)
+    );
+    let overflowed = generator.next(&clock);
+    assert_eq!(TimeIdGenerator::split(overflowed), (Millis::new(1001), 0));
+}
+
+#[test_log::test]
+fn time_id_generator_accepts_max_timestamp() {
+    let max = Millis::new(TimeIdGenerator::MAX_TIMESTAMP_MILLIS);
+    let clock = ManualClock::new(max);
+    let mut generator = TimeIdGenerator::new();
+    let first = generator.next(&clock);
+    let second = generator.next(&clock);
+    assert!(second > first);
+    assert_eq!(TimeIdGenerator::split(second), (max, 1));
+}
+
+#[test_log::test]
+#[should_panic(expected = "TimeIdGenerator timestamp does not fit in 52 bits")]
+fn time_id_generator_rejects_timestamp_beyond_52_bits() {
+    let clock = ManualClock::new(Millis::new(1 << 52));
+    TimeIdGenerator::new().next(&clock);
+}
+
+#[test_log::test]
+fn interval_timer_laps() {
+    let clock = ManualClock::new(Millis::new(1000));
+    let timer = IntervalTimer::new(&clock);
+    clock.advance(MillisDuration::from_millis(16));
+    assert_eq!(timer.lap(&clock), MillisDuration::from_millis(16));
+    clock.advance(MillisDuration::from_millis(20));
+    assert_eq!(timer.lap(&clock), MillisDuration::from_millis(20));
+    assert_eq!(timer.lap(&clock), MillisDuration::from_millis(0));
+    assert_eq!(timer.marker(), Millis::new(1036));
+
+    clock.set(Millis::new(500));
+    assert_eq!(timer.lap(&clock), MillisDuration::from_millis(0));
+}
+
+#[test_log::test]
+fn from_lower_within_custom_threshold() {
+    let sent = Millis::new(0x0001_fff0);
+    let max_skew = MillisDuration::from_millis(100);
+    let at_limit = sent + max_skew;
+    assert_eq!(
+        at_limit.from_lower_within(sent.to_lower(), max_skew),
+        Some(sent)
+    );
+    let past_limit = at_limit + MillisDuration::from_millis(1);
+    assert_eq!(
+        past_limit.from_lower_within(sent.to_lower(), max_skew),
+        None
+    );
+
+    let late = sent + MillisDuration::from_millis(10_000);
+    assert_eq!(late.from_lower(sent.to_lower()), None);
+    assert_eq!(
+        late.from_lower_within(sent.to_lower(), MillisDuration::from_millis(10_000)),
+        Some(sent)
+    );
+}
+
+#[test_log::test]
+fn normalized_in_span() {
+    let start = Millis::new(1000);
+    let end = Millis::new(3000);
+    assert_eq!(Millis::new(2000).normalized_in(start, end), 0.5);
+    assert_eq!(start.normalized_in(start, end), 0.0);
+    assert_eq!(end.normalized_in(start, end), 1.0);
+    assert_eq!(Millis::new(0).normalized_in(start, end), 0.0);
+    assert_eq!(Millis::new(9000).normalized_in(start, end), 1.0);
+    assert_eq!(Millis::new(2000).normalized_in(start, start), 0.0);
+}
+
+#[test_log::test]
+fn try_from_lower_reports_skew() {
+    let sent = Millis::new(0x0001_fff0);
+    let now = sent + MillisDuration::from_millis(100);
+    assert_eq!(now.try_from_lower(sent.to_lower()), Ok(sent));
+
+    let late = sent + MillisDuration::from_millis(4500);
+    let error = late.try_from_lower(sent.to_lower()).unwrap_err();
+    assert_eq!(
+        error,
+        FromLowerError::SkewExceeded {
+            diff: MillisDuration::from_millis(4500),
+            threshold: MillisDuration::from_millis(3000),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "reconstructed timestamp is 4500 ms old, more than the 3000 ms threshold"
+    );
+    assert_eq!(late.from_lower(sent.to_lower()), None);
+}
+
+#[test_log::test]
+fn try_from_lower_before_epoch() {
+    assert_eq!(
+        Millis::new(5).try_from_lower(10),
+        Err(FromLowerError::BeforeEpoch)
+    );
+    assert_eq!(
+        Millis::reconstruct_from_lower(Millis::new(5), 10, MillisDuration::from_millis(u64::MAX)),
+        None
+    );
+}
+
+#[test_log::test]
+fn from_lower_before_epoch_returns_none() {
+    // The wrapped reconstruction would be u64::MAX, which is only 1001 ms "before" now
+    // with wrapping arithmetic and used to be returned as a valid timestamp.
+    let now = Millis::new(1000);
+    assert_eq!(now.from_lower(0xffff), None);
+    assert_eq!(
+        now.from_lower_within(0xffff, MillisDuration::from_millis(u64::MAX)),
+        None
+    );
+    assert_eq!(now.from_lower(now.to_lower()), Some(now));
+}
+
+#[test_log::test]
+fn duration_division_integer_vs_float() {
+    let elapsed = MillisDuration::from_millis(1500);
+    let second = MillisDuration::SECOND;
+    assert_eq!(elapsed.div_duration_floor(second), 1);
+    assert_eq!(elapsed.div_duration_ceil(second), 2);
+    assert_eq!(elapsed.ratio_of(second), Some(1.5));
+    assert_eq!(elapsed.ratio_of(MillisDuration::from_millis(0)), None);
+}
+
+#[test_log::test]
+fn stopwatch_lap_and_elapsed() {
+    let clock = ManualClock::new(Millis::new(1000));
+    let mut stopwatch = Stopwatch::start(&clock);
+    clock.advance(MillisDuration::from_millis(100));
+    assert_eq!(stopwatch.lap(), MillisDuration::from_millis(100));
+    clock.advance(MillisDuration::from_millis(40));
+    assert_eq!(stopwatch.lap(), MillisDuration::from_millis(40));
+    assert_eq!(stopwatch.elapsed(), MillisDuration::from_millis(140));
+
+    stopwatch.restart();
+    clock.advance(MillisDuration::from_millis(10));
+    assert_eq!(stopwatch.elapsed(), MillisDuration::from_millis(10));
+    assert_eq!(stopwatch.lap(), MillisDuration::from_millis(10));
+}
+
+#[test_log::test]
+fn stopwatch_saturates_when_clock_is_set_back() {
+    let clock = ManualClock::new(Millis::new(1000));
+    let mut stopwatch = Stopwatch::start(&clock);
+    clock.set(Millis::new(900));
+    assert_eq!(stopwatch.elapsed(), MillisDuration::from_millis(0));
+    assert_eq!(stopwatch.lap(), MillisDuration::from_millis(0));
+    clock.advance(MillisDuration::from_millis(30));
+    assert_eq!(stopwatch.lap(), MillisDuration::from_millis(30));
+    assert_eq!(stopwatch.elapsed(), MillisDuration::from_millis(0));
+}
+
+#[test_log::test]
+fn micros_millis_conversion_truncates() {
+    assert_eq!(Micros::from(Millis::new(1500)), Micros::new(1_500_000));
+    assert_eq!(Millis::from(Micros::new(1_500_999)), Millis::new(1500));
+    assert_eq!(Millis::from(Micros::new(999)), Millis::new(0));
+    assert_eq!(
+        MillisDuration::from(MicrosDuration::from_micros(2_999)),
+        MillisDuration::from_millis(2)
+    );
+    assert_eq!(
+        MicrosDuration::from(MillisDuration::from_millis(3)),
+        MicrosDuration::from_micros(3000)
+    );
+    assert_eq!(
+        Micros::new(2_000_250).duration_since(Micros::new(1_000_000)),
+        MicrosDuration::from_micros(1_000_250)
+    );
+    assert_eq!(Micros::new(1).checked_duration_since(Micros::new(2)), None);
+}
+
+#[test_log::test]
+fn instant_clock_now_micros_shares_epoch() {
+    let clock = InstantMonotonicClock::with_base(Millis::new(5000));
+    let before = clock.now();
+    let micros = clock.now_micros();
+    let after = clock.now();
+    assert!(Millis::from(micros) >= before);
+    assert!(Millis::from(micros) <= after);
+}
+
+#[test_log::test]
+fn instant_clock_now_micros_saturates_for_large_base() {
+    let clock = InstantMonotonicClock::with_base(Millis::new(u64::MAX / 1000 + 1));
+    assert_eq!(clock.now_micros(), Micros::new(u64::MAX));
+    assert!(clock.now() >= Millis::new(u64::MAX / 1000 + 1));
+}
+
+#[test_log::test]
+fn sum_durations() {
+    let durations: Vec<MillisDuration> = [16, 17, 16, 50]
+        .into_iter()
+        .map(MillisDuration::from_millis)
+        .collect();
+    let mut manual = MillisDuration::default();
+    for duration in &durations {
+        manual += *duration;
+    }
+    assert_eq!(durations.iter().sum::<MillisDuration>(), manual);
+    assert_eq!(durations.iter().copied().sum::<MillisDuration>(), manual);
+    assert_eq!(
+        std::iter::empty::<MillisDuration>().sum::<MillisDuration>(),
+        MillisDuration::default()
+    );
+}
+
+#[test_log::test]
+#[should_panic(expected = "overflow on add millisduration")]
+fn sum_durations_overflow() {
+    let _: MillisDuration = [u64::MAX, 1]
+        .map(MillisDuration::from_millis)
+        .into_iter()
+        .sum();
+}
+
+#[test_log::test]
+fn low_tracker_follows_multiple_wraps() {
+    let start = Millis::new(0x5_0000);
+    let mut tracker = LowTracker::new(start);
+    let mut expected = start;
+    for _ in 0..10 {
+        expected += MillisDuration::from_millis(20_000);
+        assert_eq!(tracker.update(expected.to_lower()), Some(expected));
+    }
+    assert_eq!(tracker.latest(), Millis::new(0x5_0000 + 200_000));
+    assert_eq!(tracker.generation(), (0x5_0000 + 200_000) >> 16);
+}
+
+#[test_log::test]
+fn low_tracker_resolves_reordered_lower_bits() {
+    let mut tracker = LowTracker::new(Millis::new(0x2_fff0));
+    assert_eq!(tracker.update(0x0010), Some(Millis::new(0x3_0010)));
+    assert_eq!(tracker.generation(), 3);
+    assert_eq!(tracker.update(0xfff8), Some(Millis::new(0x2_fff8)));
+    assert_eq!(tracker.generation(), 3);
+    assert_eq!(tracker.update(0x0020), Some(Millis::new(0x3_0020)));
+}
+
+#[test_log::test]
+fn low_tracker_rejects_wrap_before_epoch() {
+    let mut tracker = LowTracker::new(Millis::new(100));
+    assert_eq!(tracker.update(0xfff0), None);
+    assert_eq!(tracker.latest(), Millis::new(100));
+
+    let mut tracker = LowTracker::new(Millis::new(0));
+    assert_eq!(tracker.update(0x8000), None);
+    assert_eq!(tracker.update(0x7fff), Some(Millis::new(0x7fff)));
+    assert_eq!(tracker.latest(), Millis::new(0x7fff));
+}
+
+#[cfg(feature = "time")]
+#[test_log::test]
+fn time_crate_clock_is_non_decreasing() {
+    use monotonic_time_rs::TimeCrateClock;
+
+    let clock = TimeCrateClock::new();
+    let mut previous = clock.now();
+    for _ in 0..100 {
+        let now = clock.now();
+        assert!(now >= previous);
+        previous = now;
+    }
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    assert!(clock.now() >= Millis::new(20));
+}
+
+#[test_log::test]
+fn moving_average_window_overwrites_old_samples() {
+    let mut window = MovingAverageDuration::new(3);
+    assert_eq!(window.average(), None);
+    for millis in [100, 10, 20, 30] {
+        window.push(MillisDuration::from_millis(millis));
+    }
+    assert_eq!(window.len(), 3);
+    assert_eq!(window.average(), Some(MillisDuration::from_millis(20)));
+    assert_eq!(window.min(), Some(MillisDuration::from_millis(10)));
+    assert_eq!(window.max(), Some(MillisDuration::from_millis(30)));
+
+    window.push(MillisDuration::from_millis(41));
+    assert_eq!(window.average(), Some(MillisDuration::from_millis(30)));
+    assert_eq!(window.min(), Some(MillisDuration::from_millis(20)));
+
+    window.clear();
+    assert!(window.is_empty());
+    assert_eq!(window.average(), None);
+}
+
+#[test_log::test]
+fn moving_average_does_not_overflow() {
+    let mut window = MovingAverageDuration::new(2);
+    window.push(MillisDuration::from_millis(u64::MAX));
+    window.push(MillisDuration::from_millis(u64::MAX));
+    assert_eq!(
+        window.average(),
+        Some(MillisDuration::from_millis(u64::MAX))
+    );
+}
+
+#[test_log::test]
+fn classify_latency_boundaries() {
+    let normal_max = MillisDuration::from_millis(100);
+    let slow_max = MillisDuration::from_millis(500);
+    let classify = |millis| MillisDuration::from_millis(millis).classify(normal_max, slow_max);
+
+    assert_eq!(classify(0), LatencyClass::Fast);
+    assert_eq!(classify(50), LatencyClass::Fast);
+    assert_eq!(classify(51), LatencyClass::Normal);
+    assert_eq!(classify(100), LatencyClass::Normal);
+    assert_eq!(classify(101), LatencyClass::Slow);
+    assert_eq!(classify(500), LatencyClass::Slow);
+    assert_eq!(classify(501), LatencyClass::Critical);
+    assert!(LatencyClass::Fast < LatencyClass::Critical);
+}
+
+#[test_log::test]
+fn classify_fast_cut_off_at_half_of_odd_normal_max() {
+    // Half of 101 ms truncates to 50 ms.
+    let normal_max = MillisDuration::from_millis(101);
+    let slow_max = MillisDuration::from_millis(500);
+    let classify = |millis| MillisDuration::from_millis(millis).classify(normal_max, slow_max);
+
+    assert_eq!(classify(50), LatencyClass::Fast);
+    assert_eq!(classify(51), LatencyClass::Normal);
+    assert_eq!(classify(101), LatencyClass::Normal);
+    assert_eq!(classify(102), LatencyClass::Slow);
+
+    let classify_tiny = |millis| {
+        MillisDuration::from_millis(millis).classify(MillisDuration::from_millis(1), slow_max)
+    };
+    assert_eq!(classify_tiny(0), LatencyClass::Fast);
+    assert_eq!(classify_tiny(1), LatencyClass::Normal);
+}
+
+#[test_log::test]
+fn classify_with_explicit_fast_max() {
+    let fast_max = MillisDuration::from_millis(20);
+    let normal_max = MillisDuration::from_millis(100);
+    let slow_max = MillisDuration::from_millis(500);
+    let classify =
+        |millis| MillisDuration::from_millis(millis).classify_with(fast_max, normal_max, slow_max);
+
+    assert_eq!(classify(20), LatencyClass::Fast);
+    assert_eq!(classify(21), LatencyClass::Normal);
+    assert_eq!(classify(100), LatencyClass::Normal);
+    assert_eq!(classify(101), LatencyClass::Slow);
+    assert_eq!(classify(501), LatencyClass::Critical);
+
+    let threshold = MillisDuration::from_millis(100);
+    assert_eq!(
+        threshold.classify_with(threshold, threshold, threshold),
+        LatencyClass::Fast
+    );
+}
+
+#[test_log::test]
+#[should_panic(expected = "classify fast_max must not be greater than normal_max")]
+fn classify_with_rejects_fast_max_above_normal_max() {
+    MillisDuration::from_millis(10).classify_with(
+        MillisDuration::from_millis(200),
+        MillisDuration::from_millis(100),
+        MillisDuration::from_millis(500),
+    );
+}
+
+#[test_log::test]
+#[should_panic(expected = "classify normal_max must not be greater than slow_max")]
+fn classify_rejects_inverted_thresholds() {
+    MillisDuration::from_millis(10).classify(
+        MillisDuration::from_millis(500),
+        MillisDuration::from_millis(100),
+    );
+}
+
+#[test_log::test]
+fn rate_period_from_hz() {
+    let tick_rate = Rate::from_hz(60.0).unwrap();
+    assert_eq!(tick_rate.per_second(), 60.0);
+    assert_eq!(tick_rate.period(), MillisDuration::FRAME_60HZ);
+    assert_eq!(tick_rate.period(), MillisDuration::from_millis(16));
+    assert_eq!(
+        Rate::from_hz(30.0).unwrap().period(),
+        MillisDuration::from_millis(33)
+    );
+    assert_eq!(
+        Rate::from_hz(0.5).unwrap().period(),
+        MillisDuration::from_millis(2000)
+    );
+}
+
+#[test_log::test]
+fn rate_rejects_non_positive_hz() {
+    assert_eq!(Rate::from_hz(0.0), Err("must be a positive value"));
+    assert_eq!(Rate::from_hz(-60.0), Err("must be a positive value"));
+    assert_eq!(Rate::from_hz(f32::NAN), Err("must be a number"));
+}
+
+#[test_log::test]
+fn float_constructors_report_the_same_errors() {
+    for (value, expected) in [
+        (f64::NAN, "must be a number"),
+        (f64::INFINITY, "must be a number"),
+        (f64::NEG_INFINITY, "must be a number"),
+        (-0.5, "must be a positive value"),
+        (1e20, "value is too large"),
+    ] {
+        assert_eq!(Millis::from_secs_f64(value), Err(expected));
+        assert_eq!(MillisDuration::from_secs_f64(value), Err(expected));
+    }
+    assert_eq!(Rate::from_hz(f32::NAN), Err("must be a number"));
+    assert_eq!(Rate::from_hz(f32::INFINITY), Err("must be a number"));
+    assert_eq!(Rate::from_hz(-0.5), Err("must be a positive value"));
+}